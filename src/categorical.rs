@@ -0,0 +1,189 @@
+//! Utah categorical support
+//!
+//! A categorical holds a column of strings from a small set of values as `u32` codes into a
+//! dictionary of categories. The dictionary is shared between a categorical and the ones taken
+//! from it, so comparing and grouping entries only looks at their codes.
+
+use util::error::*;
+use mixedtypes::InnerType;
+use std::rc::Rc;
+use std::collections::HashMap;
+
+/// A dictionary-encoded column of strings. Each entry is the code of its category, or `None`
+/// where the entry is empty. Ordered categories sort by their position in the dictionary;
+/// unordered ones sort like their strings.
+#[derive(Debug, Clone)]
+pub struct Categorical {
+    codes: Vec<Option<u32>>,
+    categories: Rc<Vec<String>>,
+    ordered: bool,
+}
+
+impl Categorical {
+    /// Encode `values`, using their distinct values in sorted order as the categories.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let cat = Categorical::new(&["NY", "CA", "NY", "TX"]);
+    /// assert_eq!(cat.categories(), &["CA", "NY", "TX"]);
+    /// assert_eq!(cat.codes(), &[Some(1), Some(0), Some(1), Some(2)]);
+    /// ```
+    pub fn new<U: AsRef<str>>(values: &[U]) -> Categorical {
+        let mut categories: Vec<String> = values.iter().map(|x| x.as_ref().to_string()).collect();
+        categories.sort();
+        categories.dedup();
+        Categorical::encode(values.iter().map(|x| Some(x.as_ref())),
+                            Rc::new(categories),
+                            false)
+    }
+
+    /// Encode `values` with the given categories, in the given order. Values that are not
+    /// among the categories are left empty.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let cat = Categorical::with_categories(&["low", "high", "mid", "?"],
+    ///                                        &["low", "mid", "high"],
+    ///                                        true)
+    ///     .unwrap();
+    /// assert_eq!(cat.get(3), None);
+    /// assert_eq!(cat.argsort(), vec![0, 2, 1, 3]);
+    /// ```
+    pub fn with_categories<U, V>(values: &[U],
+                                 categories: &[V],
+                                 ordered: bool)
+                                 -> Result<Categorical>
+        where U: AsRef<str>,
+              V: AsRef<str>
+    {
+        let categories: Vec<String> = categories.iter().map(|x| x.as_ref().to_string()).collect();
+        for (i, x) in categories.iter().enumerate() {
+            if categories[..i].contains(x) {
+                return Err(ErrorKind::DuplicateCategory(x.clone()).into());
+            }
+        }
+        Ok(Categorical::encode(values.iter().map(|x| Some(x.as_ref())),
+                               Rc::new(categories),
+                               ordered))
+    }
+
+    /// Encode entries that may be empty against a dictionary.
+    pub fn encode<'b, I>(values: I, categories: Rc<Vec<String>>, ordered: bool) -> Categorical
+        where I: Iterator<Item = Option<&'b str>>
+    {
+        let codes = {
+            let lookup: HashMap<&str, u32> = categories.iter()
+                .enumerate()
+                .map(|(i, c)| (&c[..], i as u32))
+                .collect();
+            values.map(|x| x.and_then(|x| lookup.get(x).cloned())).collect()
+        };
+        Categorical {
+            codes,
+            categories,
+            ordered,
+        }
+    }
+
+    /// The dictionary of categories.
+    pub fn categories(&self) -> &[String] {
+        &self.categories[..]
+    }
+
+    /// The code of each entry, as a position in the categories.
+    pub fn codes(&self) -> &[Option<u32>] {
+        &self.codes[..]
+    }
+
+    pub fn is_ordered(&self) -> bool {
+        self.ordered
+    }
+
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    /// The category of entry `i`, or `None` if it is empty.
+    pub fn get(&self, i: usize) -> Option<&str> {
+        self.codes[i].map(|c| &self.categories[c as usize][..])
+    }
+
+    /// Which entries are `value`. The value is looked up once, and then only codes are
+    /// compared.
+    pub fn eq_value(&self, value: &str) -> Vec<bool> {
+        let code = self.categories.iter().position(|c| c == value).map(|x| x as u32);
+        self.codes.iter().map(|x| x.is_some() && *x == code).collect()
+    }
+
+    /// The positions of the entries of each category, in the order of the categories.
+    /// Empty entries are in no group.
+    pub fn groups(&self) -> Vec<Vec<usize>> {
+        let mut groups = vec![Vec::new(); self.categories.len()];
+        for (i, x) in self.codes.iter().enumerate() {
+            if let Some(c) = *x {
+                groups[c as usize].push(i);
+            }
+        }
+        groups
+    }
+
+    /// The positions of the entries in sorted order, with empty entries last. The sort is
+    /// stable.
+    pub fn argsort(&self) -> Vec<usize> {
+        let mut rank: Vec<usize> = (0..self.categories.len()).collect();
+        if !self.ordered {
+            let mut by_name = rank.clone();
+            by_name.sort_by(|&a, &b| self.categories[a].cmp(&self.categories[b]));
+            for (r, &c) in by_name.iter().enumerate() {
+                rank[c] = r;
+            }
+        }
+        let mut positions: Vec<usize> = (0..self.codes.len()).collect();
+        positions.sort_by_key(|&i| self.codes[i].map_or(rank.len(), |c| rank[c as usize]));
+        positions
+    }
+
+    /// The entries at `positions`, sharing this dictionary.
+    pub fn take(&self, positions: &[usize]) -> Categorical {
+        Categorical {
+            codes: positions.iter().map(|&i| self.codes[i]).collect(),
+            categories: self.categories.clone(),
+            ordered: self.ordered,
+        }
+    }
+
+    /// The entries at `positions`, or empty entries where there is no position, sharing this
+    /// dictionary.
+    pub fn reindex(&self, positions: &[Option<usize>]) -> Categorical {
+        Categorical {
+            codes: positions.iter().map(|x| x.and_then(|i| self.codes[i])).collect(),
+            categories: self.categories.clone(),
+            ordered: self.ordered,
+        }
+    }
+
+    /// Decode the entries into strings, with empty entries as `InnerType::Empty`.
+    pub fn to_inner(&self) -> Vec<InnerType> {
+        (0..self.len())
+            .map(|i| self.get(i).map_or(InnerType::Empty, InnerType::from))
+            .collect()
+    }
+}
+
+impl PartialEq for Categorical {
+    fn eq(&self, other: &Categorical) -> bool {
+        if self.len() != other.len() || self.ordered != other.ordered {
+            return false;
+        }
+        if Rc::ptr_eq(&self.categories, &other.categories) ||
+           self.categories == other.categories {
+            self.codes == other.codes
+        } else {
+            (0..self.len()).all(|i| self.get(i) == other.get(i))
+        }
+    }
+}
@@ -0,0 +1,470 @@
+//! Utah columnar dataframe
+//!
+//! A `ColumnFrame` stores each column as a typed array, so that a frame of mixed types only
+//! pays for the type of a column once, rather than matching on an `InnerType` for every entry.
+//! Numeric columns mark empty entries like their element type does; the other columns hold an
+//! `Option` per entry.
+
+use util::error::*;
+use util::types::*;
+use util::traits::*;
+use dataframe::DataFrame;
+use mixedtypes::{InnerType, DType};
+use datetime::Datetime;
+use categorical::Categorical;
+use implement::impl_reshape::aggregate;
+use ndarray::{Array, Array1, Axis};
+use num::traits::ToPrimitive;
+use std::collections::HashMap;
+
+/// A column of a `ColumnFrame`, holding entries of a single type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+    Float(Array1<f64>),
+    Int64(Array1<i64>),
+    Int32(Array1<i32>),
+    Float32(Array1<f32>),
+    UInt64(Array1<u64>),
+    Bool(Vec<Option<bool>>),
+    Str(Vec<Option<String>>),
+    Datetime(Vec<Option<Datetime>>),
+    Categorical(Categorical),
+}
+
+/// A read-only dataframe of typed columns, labelled with strings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnFrame {
+    pub columns: Vec<String>,
+    pub data: Vec<Column>,
+    pub index: Vec<String>,
+}
+
+impl Column {
+    /// Store a sequence of entries as a column of their common type. Integer entries are stored
+    /// as floats if some entries are empty, numbers of different types as floats, and any other
+    /// mix of types as strings.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// use utah::columnar::Column;
+    /// let c = Column::from_inner(&[InnerType::Int64(1), InnerType::Empty]);
+    /// assert_eq!(c.dtype(), DType::Float);
+    /// let c = Column::from_inner(&[InnerType::from("a"), InnerType::Empty]);
+    /// assert_eq!(c, Column::Str(vec![Some("a".to_string()), None]));
+    /// ```
+    pub fn from_inner(entries: &[InnerType]) -> Column {
+        let mut dtypes: Vec<DType> = entries.iter().filter_map(|x| x.dtype()).collect();
+        dtypes.sort_by_key(|x| *x as usize);
+        dtypes.dedup();
+        let has_empty = entries.iter().any(|x| x.is_empty());
+        let numeric = |d: &DType| {
+            matches!(*d,
+                     DType::Float | DType::Int64 | DType::Int32 | DType::Float32 | DType::UInt64)
+        };
+        let dtype = match (dtypes.len(), dtypes.first()) {
+            (0, _) => DType::Float,
+            (1, Some(&d)) if !(has_empty && numeric(&d) && d != DType::Float32) => d,
+            _ if dtypes.iter().all(&numeric) => DType::Float,
+            _ => DType::Str,
+        };
+        let values: Vec<Option<InnerType>> = entries.iter()
+            .map(|x| if x.is_empty() { None } else { x.to_dtype(dtype) })
+            .collect();
+        macro_rules! array {
+            ($variant:ident, $t:ty) => {
+                Column::$variant(Array::from_iter(values.iter().map(|x| match *x {
+                    Some(InnerType::$variant(y)) => y,
+                    _ => <$t>::empty(),
+                })))
+            }
+        }
+        match dtype {
+            DType::Float => array!(Float, f64),
+            DType::Int64 => array!(Int64, i64),
+            DType::Int32 => array!(Int32, i32),
+            DType::Float32 => array!(Float32, f32),
+            DType::UInt64 => array!(UInt64, u64),
+            DType::Bool => {
+                Column::Bool(values.into_iter()
+                    .map(|x| match x {
+                        Some(InnerType::Bool(y)) => Some(y),
+                        _ => None,
+                    })
+                    .collect())
+            }
+            DType::Str => {
+                Column::Str(values.into_iter()
+                    .map(|x| match x {
+                        Some(InnerType::Str(y)) => Some(y),
+                        _ => None,
+                    })
+                    .collect())
+            }
+            DType::Datetime => {
+                Column::Datetime(values.into_iter()
+                    .map(|x| match x {
+                        Some(InnerType::Datetime(y)) => Some(y),
+                        _ => None,
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// The type of the entries. Categorical columns hold strings.
+    pub fn dtype(&self) -> DType {
+        match *self {
+            Column::Float(_) => DType::Float,
+            Column::Int64(_) => DType::Int64,
+            Column::Int32(_) => DType::Int32,
+            Column::Float32(_) => DType::Float32,
+            Column::UInt64(_) => DType::UInt64,
+            Column::Bool(_) => DType::Bool,
+            Column::Str(_) | Column::Categorical(_) => DType::Str,
+            Column::Datetime(_) => DType::Datetime,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        match *self {
+            Column::Float(ref x) => x.len(),
+            Column::Int64(ref x) => x.len(),
+            Column::Int32(ref x) => x.len(),
+            Column::Float32(ref x) => x.len(),
+            Column::UInt64(ref x) => x.len(),
+            Column::Bool(ref x) => x.len(),
+            Column::Str(ref x) => x.len(),
+            Column::Datetime(ref x) => x.len(),
+            Column::Categorical(ref x) => x.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Entry `i`, as an `InnerType`. Integer entries are never empty, since their empty value
+    /// is also a valid one.
+    pub fn get(&self, i: usize) -> InnerType {
+        match *self {
+            Column::Float(ref x) if x[i].is_empty() => InnerType::Empty,
+            Column::Float(ref x) => InnerType::Float(x[i]),
+            Column::Int64(ref x) => InnerType::Int64(x[i]),
+            Column::Int32(ref x) => InnerType::Int32(x[i]),
+            Column::Float32(ref x) if x[i].is_empty() => InnerType::Empty,
+            Column::Float32(ref x) => InnerType::Float32(x[i]),
+            Column::UInt64(ref x) => InnerType::UInt64(x[i]),
+            Column::Bool(ref x) => x[i].map_or(InnerType::Empty, InnerType::Bool),
+            Column::Str(ref x) => x[i].as_ref().map_or(InnerType::Empty, InnerType::from),
+            Column::Datetime(ref x) => x[i].map_or(InnerType::Empty, InnerType::Datetime),
+            Column::Categorical(ref x) => x.get(i).map_or(InnerType::Empty, InnerType::from),
+        }
+    }
+
+    /// The entries as `InnerType`s.
+    pub fn to_inner(&self) -> Vec<InnerType> {
+        (0..self.len()).map(|i| self.get(i)).collect()
+    }
+
+    /// The entries at `positions`.
+    pub fn take(&self, positions: &[usize]) -> Column {
+        let positions: Vec<Option<usize>> = positions.iter().map(|&i| Some(i)).collect();
+        self.reindex(&positions[..])
+    }
+
+    /// The entries at `positions`, or empty entries where there is no position. Integer
+    /// columns with empty entries become float columns, like in `Column::from_inner`.
+    fn reindex(&self, positions: &[Option<usize>]) -> Column {
+        match *self {
+            Column::Int64(_) | Column::Int32(_) | Column::UInt64(_)
+                if positions.iter().any(|x| x.is_none()) => {
+                let entries: Vec<InnerType> = positions.iter()
+                    .map(|x| x.map_or(InnerType::Empty, |i| self.get(i)))
+                    .collect();
+                Column::from_inner(&entries[..])
+            }
+            Column::Float(ref x) => Column::Float(reindex_array(x, positions)),
+            Column::Int64(ref x) => Column::Int64(reindex_array(x, positions)),
+            Column::Int32(ref x) => Column::Int32(reindex_array(x, positions)),
+            Column::Float32(ref x) => Column::Float32(reindex_array(x, positions)),
+            Column::UInt64(ref x) => Column::UInt64(reindex_array(x, positions)),
+            Column::Bool(ref x) => Column::Bool(reindex_vec(x, positions)),
+            Column::Str(ref x) => Column::Str(reindex_vec(x, positions)),
+            Column::Datetime(ref x) => Column::Datetime(reindex_vec(x, positions)),
+            Column::Categorical(ref x) => Column::Categorical(x.reindex(positions)),
+        }
+    }
+
+    /// Reduce the non-empty entries with `aggfunc`. Numeric columns are reduced in their own
+    /// type, or as `Int64` for `Int32` columns, except for means, medians, variances and
+    /// products, which are taken as floats;
+    /// other columns can only be counted, and their mode and number of unique entries found.
+    pub fn agg(&self, aggfunc: AggFunc) -> InnerType {
+        match *self {
+            // Only float entries can be empty; an integer `0` is a value like any other.
+            Column::Float(ref x) => agg_numeric(non_empty(x), aggfunc),
+            Column::Int64(ref x) => agg_numeric(x.iter().cloned().collect(), aggfunc),
+            // The aggregates skip a zero `i32` as empty, so these are reduced as `i64`s.
+            Column::Int32(ref x) => agg_numeric(x.iter().map(|&y| y as i64).collect(), aggfunc),
+            Column::Float32(ref x) => agg_numeric(non_empty(x), aggfunc),
+            Column::UInt64(ref x) => agg_numeric(x.iter().cloned().collect(), aggfunc),
+            _ => {
+                let entries: Vec<InnerType> =
+                    self.to_inner().into_iter().filter(|x| !x.is_empty()).collect();
+                match aggfunc {
+                    AggFunc::Count | AggFunc::Mode | AggFunc::NUnique if !entries.is_empty() => {
+                        aggregate(entries, aggfunc)
+                    }
+                    _ => InnerType::Empty,
+                }
+            }
+        }
+    }
+}
+
+fn non_empty<T: UtahNum>(x: &Array1<T>) -> Vec<T> {
+    x.iter().filter(|x| !x.is_empty()).cloned().collect()
+}
+
+fn agg_numeric<T>(entries: Vec<T>, aggfunc: AggFunc) -> InnerType
+    where T: UtahNum + PartialOrd + ToPrimitive,
+          InnerType: From<T>
+{
+    if entries.is_empty() {
+        return InnerType::Empty;
+    }
+    match aggfunc {
+        AggFunc::Mean | AggFunc::Median | AggFunc::Var | AggFunc::Prod => {
+            let floats = entries.iter().filter_map(|x| x.to_f64()).collect();
+            InnerType::Float(aggregate(floats, aggfunc))
+        }
+        _ => InnerType::from(aggregate(entries, aggfunc)),
+    }
+}
+
+fn reindex_array<T>(x: &Array1<T>, positions: &[Option<usize>]) -> Array1<T>
+    where T: UtahNum
+{
+    Array::from_iter(positions.iter().map(|p| p.map_or(T::empty(), |i| x[i].clone())))
+}
+
+fn reindex_vec<T>(x: &[Option<T>], positions: &[Option<usize>]) -> Vec<Option<T>>
+    where T: Clone
+{
+    positions.iter().map(|p| p.and_then(|i| x[i].clone())).collect()
+}
+
+impl ColumnFrame {
+    /// Create a frame from columns of the same length, labelled by position.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// use utah::columnar::Column;
+    /// let df = ColumnFrame::new(vec![Column::Float(arr1(&[1.5, 2.5])),
+    ///                                Column::Str(vec![Some("a".to_string()), None])])
+    ///     .unwrap()
+    ///     .columns(&["x", "name"])
+    ///     .unwrap();
+    /// assert_eq!(df.shape(), (2, 2));
+    /// assert!(ColumnFrame::new(vec![Column::Int64(arr1(&[1])), Column::Int64(arr1(&[1, 2]))])
+    ///     .is_err());
+    /// ```
+    pub fn new(data: Vec<Column>) -> Result<ColumnFrame> {
+        let nrows = data.first().map_or(0, |x| x.len());
+        if let Some(c) = data.iter().find(|x| x.len() != nrows) {
+            return Err(ErrorKind::IndexShapeMismatch(nrows.to_string(), c.len().to_string())
+                .into());
+        }
+        Ok(ColumnFrame {
+            columns: (0..data.len()).map(String::from_position).collect(),
+            index: (0..nrows).map(String::from_position).collect(),
+            data,
+        })
+    }
+
+    /// Label the columns.
+    pub fn columns<U: AsRef<str>>(mut self, columns: &[U]) -> Result<ColumnFrame> {
+        if columns.len() != self.data.len() {
+            return Err(ErrorKind::ColumnShapeMismatch(self.data.len().to_string(),
+                                                      columns.len().to_string())
+                .into());
+        }
+        self.columns = columns.iter().map(|x| x.as_ref().to_string()).collect();
+        Ok(self)
+    }
+
+    /// Label the rows.
+    pub fn index<U: AsRef<str>>(mut self, index: &[U]) -> Result<ColumnFrame> {
+        if index.len() != self.index.len() {
+            return Err(ErrorKind::IndexShapeMismatch(self.index.len().to_string(),
+                                                     index.len().to_string())
+                .into());
+        }
+        self.index = index.iter().map(|x| x.as_ref().to_string()).collect();
+        Ok(self)
+    }
+
+    /// Split a mixed dataframe into typed columns, like `Column::from_inner`.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[InnerType::from("NY"), InnerType::Int64(3)],
+    ///                [InnerType::from("CA"), InnerType::Int64(5)]]);
+    /// let df: DataFrame<InnerType> = DataFrame::new(a).columns(&["state", "n"]).unwrap();
+    /// let cf = ColumnFrame::from_df(&df);
+    /// assert_eq!(cf.column("n").unwrap().dtype(), DType::Int64);
+    /// assert_eq!(cf.to_df(), df);
+    /// ```
+    pub fn from_df(df: &DataFrame<InnerType>) -> ColumnFrame {
+        ColumnFrame {
+            columns: df.columns.clone(),
+            data: df.data
+                .axis_iter(Axis(1))
+                .map(|x| Column::from_inner(&x.iter().cloned().collect::<Vec<_>>()))
+                .collect(),
+            index: df.index.clone(),
+        }
+    }
+
+    /// Gather the columns into a mixed dataframe.
+    pub fn to_df(&self) -> DataFrame<InnerType> {
+        let (nrows, ncols) = self.shape();
+        let data = Array::from_shape_fn((nrows, ncols), |(i, j)| self.data[j].get(i));
+        DataFrame {
+            columns: self.columns.clone(),
+            data,
+            index: self.index.clone(),
+        }
+    }
+
+    /// The number of rows and columns.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.index.len(), self.columns.len())
+    }
+
+    /// The column labelled `name`.
+    pub fn column(&self, name: &str) -> Result<&Column> {
+        let j = self.position(name, UtahAxis::Column)?;
+        Ok(&self.data[j])
+    }
+
+    /// The rows or columns labelled `names`, in that order.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// use utah::columnar::Column;
+    /// let df = ColumnFrame::new(vec![Column::Int64(arr1(&[1, 2, 3])),
+    ///                                Column::Bool(vec![Some(true), None, Some(false)])])
+    ///     .unwrap()
+    ///     .columns(&["n", "ok"])
+    ///     .unwrap();
+    /// let res = df.select(&["2", "0"], UtahAxis::Row).unwrap();
+    /// assert_eq!(res.data[0], Column::Int64(arr1(&[3, 1])));
+    /// assert!(df.select(&["m"], UtahAxis::Column).is_err());
+    /// ```
+    pub fn select<U: AsRef<str>>(&self, names: &[U], axis: UtahAxis) -> Result<ColumnFrame> {
+        let positions = names.iter()
+            .map(|x| self.position(x.as_ref(), axis))
+            .collect::<Result<Vec<usize>>>()?;
+        Ok(self.take(&positions[..], axis))
+    }
+
+    /// Every row or column but those labelled `names`.
+    pub fn remove<U: AsRef<str>>(&self, names: &[U], axis: UtahAxis) -> Result<ColumnFrame> {
+        let drop = names.iter()
+            .map(|x| self.position(x.as_ref(), axis))
+            .collect::<Result<Vec<usize>>>()?;
+        let len = match axis {
+            UtahAxis::Row => self.index.len(),
+            UtahAxis::Column => self.columns.len(),
+        };
+        let positions: Vec<usize> = (0..len).filter(|i| !drop.contains(i)).collect();
+        Ok(self.take(&positions[..], axis))
+    }
+
+    /// The rows whose label is in both frames, with the columns of both.
+    pub fn inner_join(&self, other: &ColumnFrame) -> ColumnFrame {
+        let right = other.row_positions();
+        let (left, right): (Vec<usize>, Vec<Option<usize>>) = self.index
+            .iter()
+            .enumerate()
+            .filter_map(|(i, k)| right.get(&k[..]).map(|&j| (i, Some(j))))
+            .unzip();
+        self.join(other, &left[..], &right[..])
+    }
+
+    /// Every row of this frame, with the columns of both. Rows whose label is not in `other`
+    /// are empty in its columns.
+    pub fn outer_join(&self, other: &ColumnFrame) -> ColumnFrame {
+        let right = other.row_positions();
+        let left: Vec<usize> = (0..self.index.len()).collect();
+        let right: Vec<Option<usize>> =
+            self.index.iter().map(|k| right.get(&k[..]).cloned()).collect();
+        self.join(other, &left[..], &right[..])
+    }
+
+    /// Reduce every column with `aggfunc`, like `Column::agg`, into a row labelled by the
+    /// reduction.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// use utah::columnar::Column;
+    /// let df = ColumnFrame::new(vec![Column::Int64(arr1(&[1, 2, 6])),
+    ///                                Column::Str(vec![Some("a".to_string()), None, None])])
+    ///     .unwrap();
+    /// let res = df.agg(AggFunc::Mean).unwrap();
+    /// assert_eq!(res.data, arr2(&[[InnerType::Float(3.), InnerType::Empty]]));
+    /// ```
+    pub fn agg(&self, aggfunc: AggFunc) -> Result<DataFrame<InnerType>> {
+        let row: Vec<InnerType> = self.data.iter().map(|x| x.agg(aggfunc)).collect();
+        let data = Array::from_shape_vec((1, row.len()), row).unwrap();
+        DataFrame::new(data)
+            .columns(&self.columns[..])?
+            .index(&[format!("{:?}", aggfunc).to_lowercase()])
+    }
+
+    fn position(&self, name: &str, axis: UtahAxis) -> Result<usize> {
+        let labels = match axis {
+            UtahAxis::Row => &self.index,
+            UtahAxis::Column => &self.columns,
+        };
+        labels.iter()
+            .position(|x| x == name)
+            .ok_or_else(|| ErrorKind::InvalidColumnName(name.to_string()).into())
+    }
+
+    fn take(&self, positions: &[usize], axis: UtahAxis) -> ColumnFrame {
+        match axis {
+            UtahAxis::Row => {
+                ColumnFrame {
+                    columns: self.columns.clone(),
+                    data: self.data.iter().map(|x| x.take(positions)).collect(),
+                    index: positions.iter().map(|&i| self.index[i].clone()).collect(),
+                }
+            }
+            UtahAxis::Column => {
+                ColumnFrame {
+                    columns: positions.iter().map(|&j| self.columns[j].clone()).collect(),
+                    data: positions.iter().map(|&j| self.data[j].clone()).collect(),
+                    index: self.index.clone(),
+                }
+            }
+        }
+    }
+
+    fn row_positions(&self) -> HashMap<&str, usize> {
+        self.index.iter().enumerate().map(|(i, k)| (&k[..], i)).collect()
+    }
+
+    fn join(&self, other: &ColumnFrame, left: &[usize], right: &[Option<usize>]) -> ColumnFrame {
+        let mut data: Vec<Column> = self.data.iter().map(|x| x.take(left)).collect();
+        data.extend(other.data.iter().map(|x| x.reindex(right)));
+        let mut columns = self.columns.clone();
+        columns.extend(other.columns.iter().cloned());
+        ColumnFrame {
+            columns,
+            data,
+            index: left.iter().map(|&i| self.index[i].clone()).collect(),
+        }
+    }
+}
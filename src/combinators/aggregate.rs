@@ -6,22 +6,27 @@ use util::traits::*;
 use dataframe::*;
 use ndarray::Array;
 use util::error::*;
+use std::cmp::Ordering;
+use num::traits::{FromPrimitive, ToPrimitive};
+use mixedtypes::{InnerType, OuterType};
 
 #[derive(Clone, Debug)]
-pub struct Sum<'a, I: 'a, T: 'a>
-    where I: Iterator<Item = Window<'a, T>> + 'a,
-          T: UtahNum
+pub struct Sum<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>> + 'a,
+          T: UtahNum,
+          L: UtahLabel
 {
     data: I,
-    other: Vec<String>,
+    other: Vec<L>,
     axis: UtahAxis,
 }
 
-impl<'a, I, T> Sum<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+impl<'a, I, T, L> Sum<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
-    pub fn new(df: I, other: Vec<String>, axis: UtahAxis) -> Sum<'a, I, T> {
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> Sum<'a, I, T, L> {
 
         Sum {
             data: df,
@@ -31,9 +36,10 @@ impl<'a, I, T> Sum<'a, I, T>
     }
 }
 
-impl<'a, I, T> Iterator for Sum<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+impl<'a, I, T, L> Iterator for Sum<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -46,20 +52,22 @@ impl<'a, I, T> Iterator for Sum<'a, I, T>
 }
 
 #[derive(Clone, Debug)]
-pub struct Mean<'a, I: 'a, T: 'a>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+pub struct Mean<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
     data: I,
-    other: Vec<String>,
+    other: Vec<L>,
     axis: UtahAxis,
 }
 
-impl<'a, I, T> Mean<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + 'a
+impl<'a, I, T, L> Mean<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    pub fn new(df: I, other: Vec<String>, axis: UtahAxis) -> Mean<'a, I, T> {
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> Mean<'a, I, T, L> {
 
         Mean {
             data: df,
@@ -69,9 +77,10 @@ impl<'a, I, T> Mean<'a, I, T>
     }
 }
 
-impl<'a, I, T> Iterator for Mean<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + 'a
+impl<'a, I, T, L> Iterator for Mean<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -89,20 +98,22 @@ impl<'a, I, T> Iterator for Mean<'a, I, T>
 
 
 #[derive(Clone)]
-pub struct Max<'a, I: 'a, T: 'a>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+pub struct Max<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
     data: I,
-    other: Vec<String>,
+    other: Vec<L>,
     axis: UtahAxis,
 }
 
-impl<'a, I, T> Max<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + 'a
+impl<'a, I, T, L> Max<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    pub fn new(df: I, other: Vec<String>, axis: UtahAxis) -> Max<'a, I, T> {
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> Max<'a, I, T, L> {
 
         Max {
             data: df,
@@ -112,9 +123,10 @@ impl<'a, I, T> Max<'a, I, T>
     }
 }
 
-impl<'a, I, T> Iterator for Max<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + Ord + 'a
+impl<'a, I, T, L> Iterator for Max<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + Ord + 'a,
+          L: UtahLabel
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -130,20 +142,22 @@ impl<'a, I, T> Iterator for Max<'a, I, T>
 
 
 #[derive(Clone, Debug)]
-pub struct Min<'a, I: 'a, T: 'a>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+pub struct Min<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
     data: I,
-    other: Vec<String>,
+    other: Vec<L>,
     axis: UtahAxis,
 }
 
-impl<'a, I, T> Min<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + 'a
+impl<'a, I, T, L> Min<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    pub fn new(df: I, other: Vec<String>, axis: UtahAxis) -> Min<'a, I, T> {
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> Min<'a, I, T, L> {
 
         Min {
             data: df,
@@ -153,9 +167,10 @@ impl<'a, I, T> Min<'a, I, T>
     }
 }
 
-impl<'a, I, T> Iterator for Min<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + Ord
+impl<'a, I, T, L> Iterator for Min<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + Ord,
+          L: UtahLabel
 {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
@@ -170,20 +185,22 @@ impl<'a, I, T> Iterator for Min<'a, I, T>
 }
 
 #[derive(Clone)]
-pub struct Stdev<'a, I: 'a, T: 'a>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+pub struct Stdev<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
     data: I,
-    other: Vec<String>,
+    other: Vec<L>,
     axis: UtahAxis,
 }
 
-impl<'a, I, T> Stdev<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + 'a
+impl<'a, I, T, L> Stdev<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    pub fn new(df: I, other: Vec<String>, axis: UtahAxis) -> Stdev<'a, I, T> {
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> Stdev<'a, I, T, L> {
 
         Stdev {
             data: df,
@@ -194,11 +211,625 @@ impl<'a, I, T> Stdev<'a, I, T>
 }
 
 
-impl<'a, I, T> ToDataFrame<'a, T, T> for Mean<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+#[derive(Clone, Debug)]
+pub struct Count<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    data: I,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T, L> Count<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: I, axis: UtahAxis) -> Count<'a, I, T, L> {
+
+        Count {
+            data: df,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for Count<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_labeled().map(|(_, x)| x)
+    }
+}
+
+impl<'a, I, T, L> Count<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    fn next_labeled(&mut self) -> Option<(L, T)> {
+        match self.data.next() {
+            None => None,
+            Some((name, dat)) => {
+                let count = dat.iter()
+                    .filter(|&x| !x.is_empty())
+                    .fold(T::zero(), |acc, _| acc + T::one());
+                Some((name, count))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Prod<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    data: I,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T, L> Prod<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: I, axis: UtahAxis) -> Prod<'a, I, T, L> {
+
+        Prod {
+            data: df,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for Prod<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_labeled().map(|(_, x)| x)
+    }
+}
+
+impl<'a, I, T, L> Prod<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    fn next_labeled(&mut self) -> Option<(L, T)> {
+        match self.data.next() {
+            None => None,
+            Some((name, dat)) => {
+                let prod = dat.iter()
+                    .filter(|&x| !x.is_empty())
+                    .fold(T::one(), |acc, x| acc * x.clone());
+                Some((name, prod))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Median<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    data: I,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T, L> Median<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: I, axis: UtahAxis) -> Median<'a, I, T, L> {
+
+        Median {
+            data: df,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for Median<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_labeled().map(|(_, x)| x)
+    }
+}
+
+impl<'a, I, T, L> Median<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    fn next_labeled(&mut self) -> Option<(L, T)> {
+        match self.data.next() {
+            None => None,
+            Some((name, dat)) => {
+                let sorted = sorted_nonempty(dat.iter());
+                let n = sorted.len();
+                if n == 0 {
+                    return Some((name, T::empty()));
+                }
+                if n % 2 == 1 {
+                    Some((name, sorted[n / 2].clone()))
+                } else {
+                    let two = T::one() + T::one();
+                    Some((name, (sorted[n / 2 - 1].clone() + sorted[n / 2].clone()) / two))
+                }
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Quantile<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    data: I,
+    q: f64,
+    interpolation: Interpolation,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T, L> Quantile<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    /// Create a new quantile combinator. `q` must lie in `[0, 1]`.
+    pub fn new(df: I,
+               q: f64,
+               interpolation: Interpolation,
+               axis: UtahAxis)
+               -> Result<Quantile<'a, I, T, L>> {
+        if !(0. ..=1.).contains(&q) {
+            return Err(ErrorKind::InvalidQuantile(q.to_string()).into());
+        }
+        Ok(Quantile {
+            data: df,
+            q,
+            interpolation,
+            axis,
+        })
+    }
+}
+
+impl<'a, I, T, L> Iterator for Quantile<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + ToPrimitive + FromPrimitive + 'a,
+          L: UtahLabel
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_labeled().map(|(_, x)| x)
+    }
+}
+
+impl<'a, I, T, L> Quantile<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + ToPrimitive + FromPrimitive + 'a,
+          L: UtahLabel
+{
+    fn next_labeled(&mut self) -> Option<(L, T)> {
+        match self.data.next() {
+            None => None,
+            Some((name, dat)) => {
+                let sorted = sorted_nonempty(dat.iter());
+                if sorted.is_empty() {
+                    return Some((name, T::empty()));
+                }
+                let position = self.q * (sorted.len() - 1) as f64;
+                let lower = sorted[position.floor() as usize].clone();
+                let higher = sorted[position.ceil() as usize].clone();
+                let fraction = position - position.floor();
+                let res = match self.interpolation {
+                    Interpolation::Linear => {
+                        // Interpolate as floats, since the fraction truncates to zero in an
+                        // integer type.
+                        match (lower.to_f64(), higher.to_f64()) {
+                            (Some(x), Some(y)) => {
+                                T::from_f64(x + (y - x) * fraction).unwrap_or_else(T::empty)
+                            }
+                            _ => T::empty(),
+                        }
+                    }
+                    Interpolation::Lower => lower,
+                    Interpolation::Higher => higher,
+                    Interpolation::Midpoint => (lower + higher) / (T::one() + T::one()),
+                    Interpolation::Nearest => {
+                        // Round half to even, so that ties resolve the same way every time.
+                        let rounded = position.round();
+                        let nearest = if (rounded - position).abs() == 0.5 &&
+                                         (rounded as usize) % 2 == 1 {
+                            rounded - 1.
+                        } else {
+                            rounded
+                        };
+                        sorted[nearest as usize].clone()
+                    }
+                };
+                Some((name, res))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Var<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    data: I,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T, L> Var<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: I, axis: UtahAxis) -> Var<'a, I, T, L> {
+
+        Var {
+            data: df,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for Var<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_labeled().map(|(_, x)| x)
+    }
+}
+
+impl<'a, I, T, L> Var<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    fn next_labeled(&mut self) -> Option<(L, T)> {
+        match self.data.next() {
+            None => None,
+            Some((name, dat)) => {
+                let values: Vec<T> = dat.iter().filter(|&x| !x.is_empty()).cloned().collect();
+                if values.len() < 2 {
+                    return Some((name, T::empty()));
+                }
+                let size = values.iter().fold(T::zero(), |acc, _| acc + T::one());
+                let mean = values.iter().fold(T::zero(), |acc, x| acc + x.clone()) / size.clone();
+                let squares = values.into_iter().fold(T::zero(), |acc, x| {
+                    let d = x - mean.clone();
+                    acc + d.clone() * d
+                });
+                Some((name, squares / (size - T::one())))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Mode<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    data: I,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T, L> Mode<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: I, axis: UtahAxis) -> Mode<'a, I, T, L> {
+
+        Mode {
+            data: df,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for Mode<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_labeled().map(|(_, x)| x)
+    }
+}
+
+impl<'a, I, T, L> Mode<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    fn next_labeled(&mut self) -> Option<(L, T)> {
+        match self.data.next() {
+            None => None,
+            Some((name, dat)) => {
+                // Ties are broken in favour of the smallest value.
+                let sorted = sorted_nonempty(dat.iter());
+                let mut mode = T::empty();
+                let mut best = 0;
+                let mut i = 0;
+                while i < sorted.len() {
+                    let mut j = i + 1;
+                    while j < sorted.len() && sorted[j] == sorted[i] {
+                        j += 1;
+                    }
+                    if j - i > best {
+                        best = j - i;
+                        mode = sorted[i].clone();
+                    }
+                    i = j;
+                }
+                Some((name, mode))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct NUnique<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    data: I,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T, L> NUnique<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: I, axis: UtahAxis) -> NUnique<'a, I, T, L> {
+
+        NUnique {
+            data: df,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for NUnique<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_labeled().map(|(_, x)| x)
+    }
+}
+
+impl<'a, I, T, L> NUnique<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    fn next_labeled(&mut self) -> Option<(L, T)> {
+        match self.data.next() {
+            None => None,
+            Some((name, dat)) => {
+                let mut sorted = sorted_nonempty(dat.iter());
+                sorted.dedup();
+                let unique = sorted.iter().fold(T::zero(), |acc, _| acc + T::one());
+                Some((name, unique))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IdxMax<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    data: I,
+    other: Vec<L>,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T, L> IdxMax<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> IdxMax<'a, I, T, L> {
+
+        IdxMax {
+            data: df,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for IdxMax<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    type Item = Option<L>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_labeled().map(|(_, label)| label)
+    }
+}
+
+impl<'a, I, T, L> IdxMax<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    fn next_labeled(&mut self) -> Option<(L, Option<L>)> {
+        match self.data.next() {
+            None => None,
+            Some((name, dat)) => {
+                let position = extreme_position(dat.iter(), Ordering::Greater);
+                Some((name, position.map(|i| self.other[i].clone())))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct IdxMin<'a, I: 'a, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    data: I,
+    other: Vec<L>,
+    axis: UtahAxis,
+}
+
+impl<'a, I, T, L> IdxMin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> IdxMin<'a, I, T, L> {
+
+        IdxMin {
+            data: df,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for IdxMin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    type Item = Option<L>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_labeled().map(|(_, label)| label)
+    }
+}
+
+impl<'a, I, T, L> IdxMin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + 'a,
+          L: UtahLabel
+{
+    fn next_labeled(&mut self) -> Option<(L, Option<L>)> {
+        match self.data.next() {
+            None => None,
+            Some((name, dat)) => {
+                let position = extreme_position(dat.iter(), Ordering::Less);
+                Some((name, position.map(|i| self.other[i].clone())))
+            }
+        }
+    }
+}
+
+/// Find the position of the first non-empty value that is extreme in the direction of `order`.
+/// Later values only replace the current extreme if they are strictly beyond it, so ties resolve
+/// to the first occurrence. Returns `None` if every value is empty.
+fn extreme_position<'a, T, I>(values: I, order: Ordering) -> Option<usize>
+    where T: UtahNum + PartialOrd + 'a,
+          I: Iterator<Item = &'a T>
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    let mut best: Option<(usize, &T)> = None;
+    for (i, x) in values.enumerate() {
+        if x.is_empty() {
+            continue;
+        }
+        best = match best {
+            Some((j, y)) => {
+                if x.partial_cmp(y) == Some(order) {
+                    Some((i, x))
+                } else {
+                    Some((j, y))
+                }
+            }
+            None => Some((i, x)),
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+/// Collect the non-empty values of a window in ascending order.
+fn sorted_nonempty<'a, T, I>(values: I) -> Vec<T>
+    where T: UtahNum + PartialOrd + 'a,
+          I: Iterator<Item = &'a T>
+{
+    let mut sorted: Vec<T> = values.filter(|x| !x.is_empty()).cloned().collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+    sorted
+}
+
+
+/// Lay out one reduced value per window as a column, for windows along rows, or as a row, for
+/// windows along columns. The values are labelled with the names of their windows.
+fn reduced_df<T, L>(names: Vec<L>, c: Vec<T>, axis: UtahAxis) -> Result<DataFrame<T, L>>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    let d = reduced_matrix(c, axis);
+    let def = [L::from_position(0)];
+    match axis {
+        UtahAxis::Row => DataFrame::new(d).columns(&def[..])?.index(&names[..]),
+        UtahAxis::Column => DataFrame::new(d).columns(&names[..])?.index(&def[..]),
+    }
+}
+
+fn reduced_matrix<T>(c: Vec<T>, axis: UtahAxis) -> Matrix<T> {
+    let res_dim = match axis {
+        UtahAxis::Row => (c.len(), 1),
+        UtahAxis::Column => (1, c.len()),
+    };
+    Array::from_shape_vec(res_dim, c).unwrap()
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Mean<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
         let other = self.other.clone();
         let axis = self.axis.clone();
         let c: Vec<_> = self.collect();
@@ -210,7 +841,7 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Mean<'a, I, T>
 
 
         let d = Array::from_shape_vec(res_dim, c).unwrap();
-        let def = vec!["0"];
+        let def = [L::from_position(0)];
         match axis {
             UtahAxis::Row => {
                 let df = DataFrame::new(d).columns(&def[..])?.index(&other[..])?;
@@ -246,11 +877,12 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Mean<'a, I, T>
 
 
 
-impl<'a, I, T> ToDataFrame<'a, T, T> for Max<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + Ord
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Max<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + Ord,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn as_df(self) -> Result<DataFrame<T, L>> {
         let other = self.other.clone();
         let axis = self.axis.clone();
         let c: Vec<_> = self.collect();
@@ -262,7 +894,7 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Max<'a, I, T>
 
 
         let d = Array::from_shape_vec(res_dim, c).unwrap();
-        let def = vec!["0"];
+        let def = [L::from_position(0)];
         match axis {
             UtahAxis::Row => {
                 let df = DataFrame::new(d).columns(&def[..])?.index(&other[..])?;
@@ -297,11 +929,12 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Max<'a, I, T>
 }
 
 
-impl<'a, I, T> ToDataFrame<'a, T, T> for Min<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum + Ord
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Min<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + Ord,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn as_df(self) -> Result<DataFrame<T, L>> {
         let other = self.other.clone();
         let axis = self.axis.clone();
         let c: Vec<_> = self.collect();
@@ -313,7 +946,7 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Min<'a, I, T>
 
 
         let d = Array::from_shape_vec(res_dim, c).unwrap();
-        let def = vec!["0"];
+        let def = [L::from_position(0)];
         match axis {
             UtahAxis::Row => {
                 let df = DataFrame::new(d).columns(&def[..])?.index(&other[..])?;
@@ -348,13 +981,14 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Min<'a, I, T>
 }
 
 
-impl<'a, I, T> ToDataFrame<'a, T, T> for Sum<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Sum<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn as_df(self) -> Result<DataFrame<T, L>> {
         let other = self.other.clone();
-        let axis = self.axis.clone();
+        let axis = self.axis;
         let c: Vec<_> = self.collect();
         let res_dim = match axis {
             UtahAxis::Row => (other.len(), 1),
@@ -362,7 +996,7 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Sum<'a, I, T>
         };
 
         let d = Array::from_shape_vec(res_dim, c).unwrap();
-        let def = vec!["0"];
+        let def = [L::from_position(0)];
         match axis {
             UtahAxis::Row => {
                 let df = DataFrame::new(d).columns(&def[..])?.index(&other[..])?;
@@ -377,7 +1011,7 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Sum<'a, I, T>
     }
     fn as_matrix(self) -> Result<Matrix<T>> {
         let other = self.other.clone();
-        let axis = self.axis.clone();
+        let axis = self.axis;
         let c: Vec<_> = self.collect();
         let res_dim = match axis {
             UtahAxis::Row => (other.len(), 1),
@@ -395,3 +1029,317 @@ impl<'a, I, T> ToDataFrame<'a, T, T> for Sum<'a, I, T>
         Ok(Array::from_vec(c))
     }
 }
+
+
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Count<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(mut self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        while let Some((name, x)) = self.next_labeled() {
+            n.push(name);
+            c.push(x);
+        }
+        reduced_df(n, c, axis)
+    }
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let c: Vec<_> = self.collect();
+        Ok(reduced_matrix(c, axis))
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+
+        let c: Vec<_> = self.collect();
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Prod<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(mut self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        while let Some((name, x)) = self.next_labeled() {
+            n.push(name);
+            c.push(x);
+        }
+        reduced_df(n, c, axis)
+    }
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let c: Vec<_> = self.collect();
+        Ok(reduced_matrix(c, axis))
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+
+        let c: Vec<_> = self.collect();
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Median<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          L: UtahLabel
+{
+    fn as_df(mut self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        while let Some((name, x)) = self.next_labeled() {
+            n.push(name);
+            c.push(x);
+        }
+        reduced_df(n, c, axis)
+    }
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let c: Vec<_> = self.collect();
+        Ok(reduced_matrix(c, axis))
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+
+        let c: Vec<_> = self.collect();
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Quantile<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd + ToPrimitive + FromPrimitive,
+          L: UtahLabel
+{
+    fn as_df(mut self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        while let Some((name, x)) = self.next_labeled() {
+            n.push(name);
+            c.push(x);
+        }
+        reduced_df(n, c, axis)
+    }
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let c: Vec<_> = self.collect();
+        Ok(reduced_matrix(c, axis))
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+
+        let c: Vec<_> = self.collect();
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Var<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(mut self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        while let Some((name, x)) = self.next_labeled() {
+            n.push(name);
+            c.push(x);
+        }
+        reduced_df(n, c, axis)
+    }
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let c: Vec<_> = self.collect();
+        Ok(reduced_matrix(c, axis))
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+
+        let c: Vec<_> = self.collect();
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for Mode<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          L: UtahLabel
+{
+    fn as_df(mut self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        while let Some((name, x)) = self.next_labeled() {
+            n.push(name);
+            c.push(x);
+        }
+        reduced_df(n, c, axis)
+    }
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let c: Vec<_> = self.collect();
+        Ok(reduced_matrix(c, axis))
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+
+        let c: Vec<_> = self.collect();
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, T, T, L> for NUnique<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          L: UtahLabel
+{
+    fn as_df(mut self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        while let Some((name, x)) = self.next_labeled() {
+            n.push(name);
+            c.push(x);
+        }
+        reduced_df(n, c, axis)
+    }
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let c: Vec<_> = self.collect();
+        Ok(reduced_matrix(c, axis))
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+
+        let c: Vec<_> = self.collect();
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+/// An entry holding the label found by `IdxMax` or `IdxMin`, keeping its type.
+fn label_entry<L>(label: Option<L>) -> InnerType
+    where OuterType: From<L>
+{
+    label.map(|x| InnerType::from(OuterType::from(x))).unwrap_or(InnerType::Empty)
+}
+
+impl<'a, I, T, L> ToDataFrame<'a, Option<L>, InnerType, L> for IdxMax<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          L: UtahLabel,
+          OuterType: From<L>
+{
+    fn as_df(mut self) -> Result<DataFrame<InnerType, L>> {
+        let axis = self.axis;
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        while let Some((name, label)) = self.next_labeled() {
+            n.push(name);
+            c.push(label_entry(label));
+        }
+        let res_dim = match axis {
+            UtahAxis::Row => (n.len(), 1),
+            UtahAxis::Column => (1, n.len()),
+        };
+
+        let d = Array::from_shape_vec(res_dim, c).unwrap();
+        let def = [L::from_position(0)];
+        match axis {
+            UtahAxis::Row => {
+                let df = DataFrame::new(d).columns(&def[..])?.index(&n[..])?;
+                Ok(df)
+            }
+            UtahAxis::Column => {
+                let df = DataFrame::new(d).columns(&n[..])?.index(&def[..])?;
+                Ok(df)
+            }
+
+        }
+    }
+    fn as_matrix(self) -> Result<Matrix<InnerType>> {
+        let axis = self.axis;
+        let c: Vec<_> = self.map(label_entry).collect();
+        let res_dim = match axis {
+            UtahAxis::Row => (c.len(), 1),
+            UtahAxis::Column => (1, c.len()),
+        };
+
+        Ok(Array::from_shape_vec(res_dim, c).unwrap())
+    }
+
+    fn as_array(self) -> Result<Row<InnerType>> {
+
+        let c: Vec<_> = self.map(label_entry).collect();
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, Option<L>, InnerType, L> for IdxMin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          L: UtahLabel,
+          OuterType: From<L>
+{
+    fn as_df(mut self) -> Result<DataFrame<InnerType, L>> {
+        let axis = self.axis;
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        while let Some((name, label)) = self.next_labeled() {
+            n.push(name);
+            c.push(label_entry(label));
+        }
+        let res_dim = match axis {
+            UtahAxis::Row => (n.len(), 1),
+            UtahAxis::Column => (1, n.len()),
+        };
+
+        let d = Array::from_shape_vec(res_dim, c).unwrap();
+        let def = [L::from_position(0)];
+        match axis {
+            UtahAxis::Row => {
+                let df = DataFrame::new(d).columns(&def[..])?.index(&n[..])?;
+                Ok(df)
+            }
+            UtahAxis::Column => {
+                let df = DataFrame::new(d).columns(&n[..])?.index(&def[..])?;
+                Ok(df)
+            }
+
+        }
+    }
+    fn as_matrix(self) -> Result<Matrix<InnerType>> {
+        let axis = self.axis;
+        let c: Vec<_> = self.map(label_entry).collect();
+        let res_dim = match axis {
+            UtahAxis::Row => (c.len(), 1),
+            UtahAxis::Column => (1, c.len()),
+        };
+
+        Ok(Array::from_shape_vec(res_dim, c).unwrap())
+    }
+
+    fn as_array(self) -> Result<Row<InnerType>> {
+
+        let c: Vec<_> = self.map(label_entry).collect();
+        Ok(Array::from_vec(c))
+    }
+}
@@ -11,25 +11,27 @@ use util::error::*;
 use util::traits::*;
 
 #[derive(Clone, Debug)]
-pub struct Concat<'a, I, T: 'a>
-    where I: Iterator<Item = Window<'a, T>>
+pub struct Concat<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          L: UtahLabel
 {
     pub concat_data: I,
-    pub concat_other: Vec<String>,
+    pub concat_other: Vec<L>,
     pub axis: UtahAxis,
 }
 
 
 
 
-impl<'a, I, T> Concat<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>
+impl<'a, I, T, L> Concat<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          L: UtahLabel
 {
     pub fn new(left_df: I,
                right_df: I,
-               left_other: Vec<String>,
+               left_other: Vec<L>,
                axis: UtahAxis)
-               -> Concat<'a, Chain<I, I>, T> {
+               -> Concat<'a, Chain<I, I>, T, L> {
 
         let it = left_df.chain(right_df);
 
@@ -41,36 +43,39 @@ impl<'a, I, T> Concat<'a, I, T>
     }
 }
 
-impl<'a, I, T> Iterator for Concat<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>
+impl<'a, I, T, L> Iterator for Concat<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          L: UtahLabel
 {
-    type Item = Window<'a, T>;
+    type Item = Window<'a, T, L>;
     fn next(&mut self) -> Option<Self::Item> {
         self.concat_data.next()
     }
 }
 
 #[derive(Clone)]
-pub struct InnerJoin<'a, L, T>
-    where L: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum + 'a
+pub struct InnerJoin<'a, I, T, L = String>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    pub left: L,
-    pub right: HashMap<String, ArrayView1<'a, T>>,
-    pub left_columns: Vec<String>,
-    pub right_columns: Vec<String>,
+    pub left: I,
+    pub right: HashMap<L, ArrayView1<'a, T>>,
+    pub left_columns: Vec<L>,
+    pub right_columns: Vec<L>,
 }
 
-impl<'a, L, T> InnerJoin<'a, L, T>
-    where L: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum + 'a
+impl<'a, I, T, L> InnerJoin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    pub fn new<RI>(left: L,
+    pub fn new<RI>(left: I,
                    right: RI,
-                   left_columns: Vec<String>,
-                   right_columns: Vec<String>)
+                   left_columns: Vec<L>,
+                   right_columns: Vec<L>)
                    -> Self
-        where RI: Iterator<Item = Window<'a, T>>
+        where RI: Iterator<Item = Window<'a, T, L>>
     {
         InnerJoin {
             left: left,
@@ -83,11 +88,12 @@ impl<'a, L, T> InnerJoin<'a, L, T>
 
 
 
-impl<'a, L, T> Iterator for InnerJoin<'a, L, T>
-    where L: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum + 'a
+impl<'a, I, T, L> Iterator for InnerJoin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    type Item = (String, ArrayView1<'a, T>, ArrayView1<'a, T>);
+    type Item = (L, ArrayView1<'a, T>, ArrayView1<'a, T>);
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
@@ -110,27 +116,29 @@ impl<'a, L, T> Iterator for InnerJoin<'a, L, T>
 }
 
 #[derive(Clone)]
-pub struct OuterJoin<'a, L, T>
-    where L: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum + 'a
+pub struct OuterJoin<'a, I, T, L = String>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    left: L,
-    right: HashMap<String, ArrayView1<'a, T>>,
-    left_columns: Vec<String>,
-    right_columns: Vec<String>,
+    left: I,
+    right: HashMap<L, ArrayView1<'a, T>>,
+    left_columns: Vec<L>,
+    right_columns: Vec<L>,
 }
 
 
-impl<'a, L, T> OuterJoin<'a, L, T>
-    where L: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum + 'a
+impl<'a, I, T, L> OuterJoin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    pub fn new<RI>(left: L,
+    pub fn new<RI>(left: I,
                    right: RI,
-                   left_columns: Vec<String>,
-                   right_columns: Vec<String>)
+                   left_columns: Vec<L>,
+                   right_columns: Vec<L>)
                    -> Self
-        where RI: Iterator<Item = Window<'a, T>>
+        where RI: Iterator<Item = Window<'a, T, L>>
     {
         OuterJoin {
             left: left,
@@ -142,11 +150,12 @@ impl<'a, L, T> OuterJoin<'a, L, T>
 }
 
 
-impl<'a, L, T> Iterator for OuterJoin<'a, L, T>
-    where L: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum + 'a
+impl<'a, I, T, L> Iterator for OuterJoin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    type Item = (String, ArrayView1<'a, T>, Option<ArrayView1<'a, T>>);
+    type Item = (L, ArrayView1<'a, T>, Option<ArrayView1<'a, T>>);
 
     fn next(&mut self) -> Option<Self::Item> {
 
@@ -166,11 +175,13 @@ impl<'a, L, T> Iterator for OuterJoin<'a, L, T>
 }
 
 
-impl<'a, L, T> ToDataFrame<'a, (String, ArrayView1<'a, T>, ArrayView1<'a, T>), T>
-    for InnerJoin<'a, L, T>
-    where L: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum{
-    fn as_df(self) -> Result<DataFrame<T>> {
+impl<'a, I, T, L> ToDataFrame<'a, (L, ArrayView1<'a, T>, ArrayView1<'a, T>), T, L>
+    for InnerJoin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
 
         let s = self.clone();
         let right_columns = self.right_columns.clone();
@@ -232,11 +243,13 @@ impl<'a, L, T> ToDataFrame<'a, (String, ArrayView1<'a, T>, ArrayView1<'a, T>), T
 }
 
 
-impl<'a, L,T> ToDataFrame<'a, (String, ArrayView1<'a, T>, Option<ArrayView1<'a, T>>), T>
-    for OuterJoin<'a, L, T>
-    where L: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum{
-    fn as_df(self) -> Result<DataFrame<T>> {
+impl<'a, I, T, L> ToDataFrame<'a, (L, ArrayView1<'a, T>, Option<ArrayView1<'a, T>>), T, L>
+    for OuterJoin<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
 
         let s = self.clone();
         let right_columns = self.right_columns.clone();
@@ -312,11 +325,12 @@ impl<'a, L,T> ToDataFrame<'a, (String, ArrayView1<'a, T>, Option<ArrayView1<'a,
 
 
 
-impl<'a, I, T> ToDataFrame<'a, Window<'a, T>, T> for Concat<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum
+impl<'a, I, T, L> ToDataFrame<'a, Window<'a, T, L>, T, L> for Concat<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn as_df(self) -> Result<DataFrame<T, L>> {
 
         let s = self.clone();
         let axis = self.axis.clone();
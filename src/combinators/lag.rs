@@ -0,0 +1,375 @@
+//! Utah lag combinators.
+
+use util::types::*;
+use std::iter::Iterator;
+use ndarray::{Array, ArrayView1};
+use combinators::transform::*;
+use util::traits::*;
+use dataframe::*;
+use util::error::*;
+
+
+#[derive(Clone, Debug)]
+pub struct Shift<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub data: I,
+    pub n: isize,
+    pub other: Vec<L>,
+    pub axis: UtahAxis,
+}
+
+impl<'a, I, T, L> Shift<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub fn new(df: I, n: isize, other: Vec<L>, axis: UtahAxis) -> Shift<'a, I, T, L> {
+        Shift {
+            data: df,
+            n,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for Shift<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    type Item = (L, Row<T>);
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.data.next() {
+            None => None,
+            Some((val, dat)) => Some((val, shifted(dat, self.n))),
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct Diff<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub data: I,
+    pub n: isize,
+    pub other: Vec<L>,
+    pub axis: UtahAxis,
+}
+
+impl<'a, I, T, L> Diff<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub fn new(df: I, n: isize, other: Vec<L>, axis: UtahAxis) -> Diff<'a, I, T, L> {
+        Diff {
+            data: df,
+            n,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for Diff<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    type Item = (L, Row<T>);
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.data.next() {
+            None => None,
+            Some((val, dat)) => {
+                let res = lagged(dat, self.n, |x, prev| Some(x.clone() - prev.clone()));
+                Some((val, res))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct PctChange<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub data: I,
+    pub n: isize,
+    pub other: Vec<L>,
+    pub axis: UtahAxis,
+}
+
+impl<'a, I, T, L> PctChange<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub fn new(df: I, n: isize, other: Vec<L>, axis: UtahAxis) -> PctChange<'a, I, T, L> {
+        PctChange {
+            data: df,
+            n,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for PctChange<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    type Item = (L, Row<T>);
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.data.next() {
+            None => None,
+            Some((val, dat)) => {
+                // A change from zero is undefined, so it is left empty rather than dividing by
+                // zero, which would panic for integer types.
+                let res = lagged(dat, self.n, |x, prev| if *prev == T::zero() {
+                    None
+                } else {
+                    Some((x.clone() - prev.clone()) / prev.clone())
+                });
+                Some((val, res))
+            }
+        }
+    }
+}
+
+
+/// Move every entry of `dat` forward by `n` positions (backward if `n` is negative), filling
+/// the vacated positions with empty values.
+fn shifted<T>(dat: ArrayView1<T>, n: isize) -> Row<T>
+    where T: UtahNum
+{
+    let len = dat.len() as isize;
+    Array::from_vec((0..len)
+        .map(|i| if i - n >= 0 && i - n < len {
+            dat[(i - n) as usize].clone()
+        } else {
+            T::empty()
+        })
+        .collect())
+}
+
+/// Combine every entry of `dat` with the entry `n` positions before it. The result is empty
+/// wherever either entry is empty or missing, or `f` gives nothing.
+fn lagged<T, F>(dat: ArrayView1<T>, n: isize, f: F) -> Row<T>
+    where T: UtahNum,
+          F: Fn(&T, &T) -> Option<T>
+{
+    let prev = shifted(dat, n);
+    Array::from_vec(dat.iter()
+        .zip(prev.iter())
+        .map(|(x, p)| if x.is_empty() || p.is_empty() {
+            T::empty()
+        } else {
+            f(x, p).unwrap_or(T::empty())
+        })
+        .collect())
+}
+
+/// Assemble lagged windows into a dataframe with the same labels as the one they came from.
+fn lagged_df<T, I, L>(windows: I, other: Vec<L>, axis: UtahAxis) -> Result<DataFrame<T, L>>
+    where T: UtahNum,
+          L: UtahLabel,
+          I: Iterator<Item = (L, Row<T>)>
+{
+    let mut c = Vec::new();
+    let mut n = Vec::new();
+    for (i, j) in windows {
+        c.extend(j.iter().cloned());
+        n.push(i);
+    }
+
+    let d = Array::from_shape_vec((n.len(), other.len()), c).unwrap();
+    match axis {
+        UtahAxis::Row => {
+            let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
+            Ok(df)
+        }
+        UtahAxis::Column => {
+            let df = DataFrame::new(d.reversed_axes()).columns(&n[..])?.index(&other[..])?;
+            Ok(df)
+        }
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, (L, Row<T>), T, L> for Shift<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        lagged_df(self, other, axis)
+    }
+
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        Ok(self.as_df()?.data)
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+        let mut c = Vec::new();
+        for (_, j) in self {
+            c.extend(j.iter().cloned());
+        }
+        Ok(Array::from_vec(c))
+    }
+}
+
+impl<'a, I, T, L> ToDataFrame<'a, (L, Row<T>), T, L> for Diff<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        lagged_df(self, other, axis)
+    }
+
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        Ok(self.as_df()?.data)
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+        let mut c = Vec::new();
+        for (_, j) in self {
+            c.extend(j.iter().cloned());
+        }
+        Ok(Array::from_vec(c))
+    }
+}
+
+impl<'a, I, T, L> ToDataFrame<'a, (L, Row<T>), T, L> for PctChange<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        lagged_df(self, other, axis)
+    }
+
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        Ok(self.as_df()?.data)
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+        let mut c = Vec::new();
+        for (_, j) in self {
+            c.extend(j.iter().cloned());
+        }
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, T, L> Lag<'a, T, L> for DataFrameIterator<'a, T, L>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    fn shift(self, n: isize) -> Shift<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Shift::new(self, n, other, axis)
+    }
+
+    fn diff(self, n: isize) -> Diff<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Diff::new(self, n, other, axis)
+    }
+
+    fn pct_change(self, n: isize) -> PctChange<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        PctChange::new(self, n, other, axis)
+    }
+}
+
+impl<'a, I, T, L> Lag<'a, T, L> for Select<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn shift(self, n: isize) -> Shift<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Shift::new(self, n, other, axis)
+    }
+
+    fn diff(self, n: isize) -> Diff<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Diff::new(self, n, other, axis)
+    }
+
+    fn pct_change(self, n: isize) -> PctChange<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        PctChange::new(self, n, other, axis)
+    }
+}
+
+impl<'a, I, T, L> Lag<'a, T, L> for Remove<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn shift(self, n: isize) -> Shift<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Shift::new(self, n, other, axis)
+    }
+
+    fn diff(self, n: isize) -> Diff<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Diff::new(self, n, other, axis)
+    }
+
+    fn pct_change(self, n: isize) -> PctChange<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        PctChange::new(self, n, other, axis)
+    }
+}
+
+impl<'a, I, T, L> Lag<'a, T, L> for Append<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn shift(self, n: isize) -> Shift<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Shift::new(self, n, other, axis)
+    }
+
+    fn diff(self, n: isize) -> Diff<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Diff::new(self, n, other, axis)
+    }
+
+    fn pct_change(self, n: isize) -> PctChange<'a, Self, T, L> {
+        let other = self.other.clone();
+        let axis = self.axis;
+        PctChange::new(self, n, other, axis)
+    }
+}
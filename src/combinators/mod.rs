@@ -2,5 +2,8 @@
 
 pub mod aggregate;
 pub mod interact;
+pub mod lag;
 pub mod process;
+pub mod resample;
+pub mod rolling;
 pub mod transform;
@@ -9,21 +9,23 @@ use util::error::*;
 
 
 #[derive(Clone, Debug)]
-pub struct MapDF<'a, T: 'a, I, F>
-    where I: Iterator<Item = WindowMut<'a, T>>,
-          F: Fn(T) -> T
+pub struct MapDF<'a, T: 'a, I, F, L = String>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          F: Fn(T) -> T,
+          L: UtahLabel
 {
     data: I,
     func: F,
-    other: Vec<String>,
+    other: Vec<L>,
     axis: UtahAxis,
 }
 
-impl<'a, T, I, F> MapDF<'a, T, I, F>
-    where I: Iterator<Item = WindowMut<'a, T>>,
-          F: Fn(T) -> T
+impl<'a, T, I, F, L> MapDF<'a, T, I, F, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          F: Fn(T) -> T,
+          L: UtahLabel
 {
-    pub fn new(df: I, f: F, other: Vec<String>, axis: UtahAxis) -> MapDF<'a, T, I, F> {
+    pub fn new(df: I, f: F, other: Vec<L>, axis: UtahAxis) -> MapDF<'a, T, I, F, L> {
 
         MapDF {
             data: df,
@@ -34,12 +36,13 @@ impl<'a, T, I, F> MapDF<'a, T, I, F>
     }
 }
 
-impl<'a, T, I, F> Iterator for MapDF<'a, T, I, F>
-    where I: Iterator<Item = WindowMut<'a, T>>,
+impl<'a, T, I, F, L> Iterator for MapDF<'a, T, I, F, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
           F: Fn(T) -> T,
-          T: Clone
+          T: Clone,
+          L: UtahLabel
 {
-    type Item = WindowMut<'a, T>;
+    type Item = WindowMut<'a, T, L>;
     fn next(&mut self) -> Option<Self::Item> {
         match self.data.next() {
             None => return None,
@@ -56,22 +59,24 @@ impl<'a, T, I, F> Iterator for MapDF<'a, T, I, F>
 
 
 #[derive(Clone)]
-pub struct Impute<'a, I, T: 'a>
-    where I: Iterator<Item = WindowMut<'a, T>> + 'a,
-          T: UtahNum
+pub struct Impute<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = WindowMut<'a, T, L>> + 'a,
+          T: UtahNum,
+          L: UtahLabel
 {
     pub data: I,
     pub strategy: ImputeStrategy,
-    pub other: Vec<String>,
+    pub other: Vec<L>,
     pub axis: UtahAxis,
 }
 
-impl<'a, I, T> Impute<'a, I, T>
-    where I: Iterator<Item = WindowMut<'a, T>>,
-          T: UtahNum
+impl<'a, I, T, L> Impute<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
-    pub fn new(df: I, s: ImputeStrategy, other: Vec<String>, axis: UtahAxis) -> Impute<'a, I, T>
-        where I: Iterator<Item = WindowMut<'a, T>>
+    pub fn new(df: I, s: ImputeStrategy, other: Vec<L>, axis: UtahAxis) -> Impute<'a, I, T, L>
+        where I: Iterator<Item = WindowMut<'a, T, L>>
     {
 
         Impute {
@@ -83,11 +88,12 @@ impl<'a, I, T> Impute<'a, I, T>
     }
 }
 
-impl<'a, I, T> Iterator for Impute<'a, I, T>
-    where I: Iterator<Item = WindowMut<'a, T>>,
-          T: UtahNum
+impl<'a, I, T, L> Iterator for Impute<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
-    type Item = WindowMut<'a, T>;
+    type Item = WindowMut<'a, T, L>;
     fn next(&mut self) -> Option<Self::Item> {
         match self.data.next() {
 
@@ -122,21 +128,245 @@ impl<'a, I, T> Iterator for Impute<'a, I, T>
 }
 
 
-impl<'a, I, T, F> Process<'a, T, F> for MapDF<'a, T, I, F>
-    where I: Iterator<Item = WindowMut<'a, T>>,
+#[derive(Clone, Debug)]
+pub struct CumSum<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = WindowMut<'a, T, L>> + 'a,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub data: I,
+    pub other: Vec<L>,
+    pub axis: UtahAxis,
+}
+
+impl<'a, I, T, L> CumSum<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> CumSum<'a, I, T, L> {
+
+        CumSum {
+            data: df,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for CumSum<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    type Item = WindowMut<'a, T, L>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.data.next() {
+            None => None,
+            Some((val, mut dat)) => {
+                let mut acc: Option<T> = None;
+                for x in dat.iter_mut() {
+                    if x.is_empty() {
+                        continue;
+                    }
+                    let res = match acc {
+                        Some(acc) => acc + x.clone(),
+                        None => x.clone(),
+                    };
+                    *x = res.clone();
+                    acc = Some(res);
+                }
+                Some((val, dat))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct CumProd<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = WindowMut<'a, T, L>> + 'a,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub data: I,
+    pub other: Vec<L>,
+    pub axis: UtahAxis,
+}
+
+impl<'a, I, T, L> CumProd<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> CumProd<'a, I, T, L> {
+
+        CumProd {
+            data: df,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for CumProd<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    type Item = WindowMut<'a, T, L>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.data.next() {
+            None => None,
+            Some((val, mut dat)) => {
+                let mut acc: Option<T> = None;
+                for x in dat.iter_mut() {
+                    if x.is_empty() {
+                        continue;
+                    }
+                    let res = match acc {
+                        Some(acc) => acc * x.clone(),
+                        None => x.clone(),
+                    };
+                    *x = res.clone();
+                    acc = Some(res);
+                }
+                Some((val, dat))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct CumMax<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = WindowMut<'a, T, L>> + 'a,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub data: I,
+    pub other: Vec<L>,
+    pub axis: UtahAxis,
+}
+
+impl<'a, I, T, L> CumMax<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> CumMax<'a, I, T, L> {
+
+        CumMax {
+            data: df,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for CumMax<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          L: UtahLabel
+{
+    type Item = WindowMut<'a, T, L>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.data.next() {
+            None => None,
+            Some((val, mut dat)) => {
+                let mut acc: Option<T> = None;
+                for x in dat.iter_mut() {
+                    if x.is_empty() {
+                        continue;
+                    }
+                    let res = match acc {
+                        Some(acc) => {
+                            if *x > acc { x.clone() } else { acc }
+                        }
+                        None => x.clone(),
+                    };
+                    *x = res.clone();
+                    acc = Some(res);
+                }
+                Some((val, dat))
+            }
+        }
+    }
+}
+
+
+#[derive(Clone, Debug)]
+pub struct CumMin<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = WindowMut<'a, T, L>> + 'a,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub data: I,
+    pub other: Vec<L>,
+    pub axis: UtahAxis,
+}
+
+impl<'a, I, T, L> CumMin<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    pub fn new(df: I, other: Vec<L>, axis: UtahAxis) -> CumMin<'a, I, T, L> {
+
+        CumMin {
+            data: df,
+            other,
+            axis,
+        }
+    }
+}
+
+impl<'a, I, T, L> Iterator for CumMin<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          L: UtahLabel
+{
+    type Item = WindowMut<'a, T, L>;
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.data.next() {
+            None => None,
+            Some((val, mut dat)) => {
+                let mut acc: Option<T> = None;
+                for x in dat.iter_mut() {
+                    if x.is_empty() {
+                        continue;
+                    }
+                    let res = match acc {
+                        Some(acc) => {
+                            if *x < acc { x.clone() } else { acc }
+                        }
+                        None => x.clone(),
+                    };
+                    *x = res.clone();
+                    acc = Some(res);
+                }
+                Some((val, dat))
+            }
+        }
+    }
+}
+
+impl<'a, I, T, F, L> Process<'a, T, F, L> for MapDF<'a, T, I, F, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
           T: UtahNum,
-          F: Fn(T) -> T
+          F: Fn(T) -> T,
+          L: UtahLabel
 {
-    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T>
-        where Self: Sized + Iterator<Item = WindowMut<'a, T>>
+    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Impute::new(self, strategy, other, axis)
     }
 
-    fn to_mut_df(self) -> DataFrameMut<'a, T>
-        where Self: Sized + Iterator<Item = WindowMut<'a, T>>
+    fn to_mut_df(self) -> DataFrameMut<'a, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
     {
 
         let axis = self.axis.clone();
@@ -179,8 +409,8 @@ impl<'a, I, T, F> Process<'a, T, F> for MapDF<'a, T, I, F>
 
     }
 
-    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F>
-        where Self: Sized + Iterator<Item = WindowMut<'a, T>>
+    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
     {
         let axis = self.axis.clone();
         let other = self.other.clone();
@@ -189,21 +419,22 @@ impl<'a, I, T, F> Process<'a, T, F> for MapDF<'a, T, I, F>
 }
 
 
-impl<'a, I, T, F> Process<'a, T, F> for Impute<'a, I, T>
-    where I: Iterator<Item = WindowMut<'a, T>>,
+impl<'a, I, T, F, L> Process<'a, T, F, L> for Impute<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
           T: UtahNum,
-          F: Fn(T) -> T
+          F: Fn(T) -> T,
+          L: UtahLabel
 {
-    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T>
-        where Self: Sized + Iterator<Item = WindowMut<'a, T>>
+    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Impute::new(self, strategy, other, axis)
     }
 
-    fn to_mut_df(self) -> DataFrameMut<'a, T>
-        where Self: Sized + Iterator<Item = WindowMut<'a, T>>
+    fn to_mut_df(self) -> DataFrameMut<'a, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
     {
 
         let axis = self.axis.clone();
@@ -246,19 +477,20 @@ impl<'a, I, T, F> Process<'a, T, F> for Impute<'a, I, T>
 
     }
 
-    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F> {
+    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F, L> {
         let axis = self.axis.clone();
         let other = self.other.clone();
         MapDF::new(self, f, other, axis)
     }
 }
 
-impl<'a, T, F> Process<'a, T, F> for DataFrameMutIterator<'a, T>
+impl<'a, T, F, L> Process<'a, T, F, L> for DataFrameMutIterator<'a, T, L>
     where T: UtahNum,
-          F: Fn(T) -> T
+          F: Fn(T) -> T,
+          L: UtahLabel
 {
-    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T>
-        where Self: Sized + Iterator<Item = WindowMut<'a, T>>
+    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
     {
 
         let other = self.other.clone();
@@ -266,8 +498,8 @@ impl<'a, T, F> Process<'a, T, F> for DataFrameMutIterator<'a, T>
         Impute::new(self, strategy, other, axis)
     }
 
-    fn to_mut_df(self) -> DataFrameMut<'a, T>
-        where Self: Sized + Iterator<Item = WindowMut<'a, T>>
+    fn to_mut_df(self) -> DataFrameMut<'a, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
     {
         // let s = self.clone();
         let axis = self.axis.clone();
@@ -310,142 +542,635 @@ impl<'a, T, F> Process<'a, T, F> for DataFrameMutIterator<'a, T>
         }
     }
 
-    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F> {
+    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F, L> {
         let axis = self.axis.clone();
         let other = self.other.clone();
         MapDF::new(self, f, other, axis)
     }
 }
 
-impl<'a, T> ToDataFrame<'a, WindowMut<'a, T>, T> for DataFrameMutIterator<'a, T>
-    where T: UtahNum
+impl<'a, I, T, F, L> Process<'a, T, F, L> for CumSum<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          F: Fn(T) -> T,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Impute::new(self, strategy, other, axis)
+    }
+
+    fn to_mut_df(self) -> DataFrameMut<'a, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
         let axis = self.axis.clone();
         let other = self.other.clone();
         let mut c = Vec::new();
         let mut n = Vec::new();
-        let (mut ncols, mut nrows) = match axis {
-            UtahAxis::Row => (other.len(), 0),
-            UtahAxis::Column => (0, other.len()),
-        };
 
         for (i, j) in self {
-            match axis {
-                UtahAxis::Row => nrows += 1,
-                UtahAxis::Column => ncols += 1,
-            };
             c.extend(j);
             n.push(i.to_owned());
         }
 
-
-        let d = Array::from_shape_vec((nrows, ncols), c).unwrap().map(|x| ((*x).clone()));
+        let data = Array::from_shape_vec((n.len(), other.len()), c).unwrap();
         match axis {
             UtahAxis::Row => {
-                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
-                Ok(df)
+                DataFrameMut {
+                    columns: other,
+                    data,
+                    index: n,
+                }
             }
             UtahAxis::Column => {
-                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
-                Ok(df)
+                DataFrameMut {
+                    columns: n,
+                    data: data.reversed_axes(),
+                    index: other,
+                }
             }
 
         }
     }
-    fn as_matrix(self) -> Result<Matrix<T>> {
+
+    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F, L> {
+        let axis = self.axis.clone();
+        let other = self.other.clone();
+        MapDF::new(self, f, other, axis)
+    }
+}
+
+
+impl<'a, I, T, F, L> Process<'a, T, F, L> for CumProd<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          F: Fn(T) -> T,
+          L: UtahLabel
+{
+    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Impute::new(self, strategy, other, axis)
+    }
+
+    fn to_mut_df(self) -> DataFrameMut<'a, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
         let axis = self.axis.clone();
         let other = self.other.clone();
         let mut c = Vec::new();
         let mut n = Vec::new();
-        let (mut ncols, mut nrows) = match axis {
-            UtahAxis::Row => (other.len(), 0),
-            UtahAxis::Column => (0, other.len()),
-        };
 
         for (i, j) in self {
-            match axis {
-                UtahAxis::Row => nrows += 1,
-                UtahAxis::Column => ncols += 1,
-            };
-
             c.extend(j);
             n.push(i.to_owned());
         }
 
-        Ok(Array::from_shape_vec((nrows, ncols), c).unwrap().map(|x| ((*x).clone())))
-
+        let data = Array::from_shape_vec((n.len(), other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => {
+                DataFrameMut {
+                    columns: other,
+                    data,
+                    index: n,
+                }
+            }
+            UtahAxis::Column => {
+                DataFrameMut {
+                    columns: n,
+                    data: data.reversed_axes(),
+                    index: other,
+                }
+            }
 
+        }
     }
 
-    fn as_array(self) -> Result<Row<T>> {
-        let mut c = Vec::new();
-        for (_, j) in self {
-            c.extend(j);
-        }
-        Ok(Array::from_vec(c).map(|x| ((*x).clone())))
+    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F, L> {
+        let axis = self.axis.clone();
+        let other = self.other.clone();
+        MapDF::new(self, f, other, axis)
     }
 }
 
-impl<'a, I, T> ToDataFrame<'a, WindowMut<'a, T>, T> for Impute<'a, I, T>
-    where I: Iterator<Item = WindowMut<'a, T>>,
-          T: UtahNum
+
+impl<'a, I, T, F, L> Process<'a, T, F, L> for CumMax<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          F: Fn(T) -> T,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
-        let axis = self.axis.clone();
+    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Impute::new(self, strategy, other, axis)
+    }
+
+    fn to_mut_df(self) -> DataFrameMut<'a, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let axis = self.axis;
         let other = self.other.clone();
         let mut c = Vec::new();
         let mut n = Vec::new();
-        let (mut ncols, mut nrows) = match axis {
-            UtahAxis::Row => (other.len(), 0),
-            UtahAxis::Column => (0, other.len()),
-        };
 
         for (i, j) in self {
-            match axis {
-                UtahAxis::Row => nrows += 1,
-                UtahAxis::Column => ncols += 1,
-            };
             c.extend(j);
             n.push(i.to_owned());
         }
 
-
-        let d = Array::from_shape_vec((nrows, ncols), c).unwrap().map(|x| ((*x).clone()));
+        let data = Array::from_shape_vec((n.len(), other.len()), c).unwrap();
         match axis {
             UtahAxis::Row => {
-                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
-                Ok(df)
+                DataFrameMut {
+                    columns: other,
+                    data,
+                    index: n,
+                }
             }
             UtahAxis::Column => {
-                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
-                Ok(df)
+                DataFrameMut {
+                    columns: n,
+                    data: data.reversed_axes(),
+                    index: other,
+                }
             }
 
         }
     }
 
-    fn as_matrix(self) -> Result<Matrix<T>> {
-        let axis = self.axis.clone();
+    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F, L> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        MapDF::new(self, f, other, axis)
+    }
+}
+
+
+impl<'a, I, T, F, L> Process<'a, T, F, L> for CumMin<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          F: Fn(T) -> T,
+          L: UtahLabel
+{
+    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        Impute::new(self, strategy, other, axis)
+    }
+
+    fn to_mut_df(self) -> DataFrameMut<'a, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let axis = self.axis;
         let other = self.other.clone();
         let mut c = Vec::new();
         let mut n = Vec::new();
-        let (mut ncols, mut nrows) = match axis {
-            UtahAxis::Row => (other.len(), 0),
-            UtahAxis::Column => (0, other.len()),
-        };
 
         for (i, j) in self {
-            match axis {
-                UtahAxis::Row => nrows += 1,
-                UtahAxis::Column => ncols += 1,
-            };
-
             c.extend(j);
             n.push(i.to_owned());
         }
 
-        Ok(Array::from_shape_vec((nrows, ncols), c).unwrap().map(|x| ((*x).clone())))
+        let data = Array::from_shape_vec((n.len(), other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => {
+                DataFrameMut {
+                    columns: other,
+                    data,
+                    index: n,
+                }
+            }
+            UtahAxis::Column => {
+                DataFrameMut {
+                    columns: n,
+                    data: data.reversed_axes(),
+                    index: other,
+                }
+            }
+
+        }
+    }
+
+    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F, L> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        MapDF::new(self, f, other, axis)
+    }
+}
+
+impl<'a, I, T, F, L> Cumulative<'a, T, L> for MapDF<'a, T, I, F, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          F: Fn(T) -> T,
+          L: UtahLabel
+{
+    fn cumsum(self) -> CumSum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumSum::new(self, other, axis)
+    }
+
+    fn cumprod(self) -> CumProd<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumProd::new(self, other, axis)
+    }
+
+    fn cummax(self) -> CumMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMax::new(self, other, axis)
+    }
+
+    fn cummin(self) -> CumMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMin::new(self, other, axis)
+    }
+}
+
+
+impl<'a, I, T, L> Cumulative<'a, T, L> for Impute<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn cumsum(self) -> CumSum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumSum::new(self, other, axis)
+    }
+
+    fn cumprod(self) -> CumProd<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumProd::new(self, other, axis)
+    }
+
+    fn cummax(self) -> CumMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMax::new(self, other, axis)
+    }
+
+    fn cummin(self) -> CumMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMin::new(self, other, axis)
+    }
+}
+
+
+impl<'a, T, L> Cumulative<'a, T, L> for DataFrameMutIterator<'a, T, L>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    fn cumsum(self) -> CumSum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumSum::new(self, other, axis)
+    }
+
+    fn cumprod(self) -> CumProd<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumProd::new(self, other, axis)
+    }
+
+    fn cummax(self) -> CumMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMax::new(self, other, axis)
+    }
+
+    fn cummin(self) -> CumMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMin::new(self, other, axis)
+    }
+}
+
+
+impl<'a, I, T, L> Cumulative<'a, T, L> for CumSum<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn cumsum(self) -> CumSum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumSum::new(self, other, axis)
+    }
+
+    fn cumprod(self) -> CumProd<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumProd::new(self, other, axis)
+    }
+
+    fn cummax(self) -> CumMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMax::new(self, other, axis)
+    }
+
+    fn cummin(self) -> CumMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMin::new(self, other, axis)
+    }
+}
+
+
+impl<'a, I, T, L> Cumulative<'a, T, L> for CumProd<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn cumsum(self) -> CumSum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumSum::new(self, other, axis)
+    }
+
+    fn cumprod(self) -> CumProd<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumProd::new(self, other, axis)
+    }
+
+    fn cummax(self) -> CumMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMax::new(self, other, axis)
+    }
+
+    fn cummin(self) -> CumMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMin::new(self, other, axis)
+    }
+}
+
+
+impl<'a, I, T, L> Cumulative<'a, T, L> for CumMax<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn cumsum(self) -> CumSum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumSum::new(self, other, axis)
+    }
+
+    fn cumprod(self) -> CumProd<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumProd::new(self, other, axis)
+    }
+
+    fn cummax(self) -> CumMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMax::new(self, other, axis)
+    }
+
+    fn cummin(self) -> CumMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMin::new(self, other, axis)
+    }
+}
+
+
+impl<'a, I, T, L> Cumulative<'a, T, L> for CumMin<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn cumsum(self) -> CumSum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumSum::new(self, other, axis)
+    }
+
+    fn cumprod(self) -> CumProd<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumProd::new(self, other, axis)
+    }
+
+    fn cummax(self) -> CumMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMax::new(self, other, axis)
+    }
+
+    fn cummin(self) -> CumMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        CumMin::new(self, other, axis)
+    }
+}
+
+impl<'a, T, L> ToDataFrame<'a, WindowMut<'a, T, L>, T, L> for DataFrameMutIterator<'a, T, L>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        let (mut ncols, mut nrows) = match axis {
+            UtahAxis::Row => (other.len(), 0),
+            UtahAxis::Column => (0, other.len()),
+        };
+
+        for (i, j) in self {
+            match axis {
+                UtahAxis::Row => nrows += 1,
+                UtahAxis::Column => ncols += 1,
+            };
+            c.extend(j);
+            n.push(i.to_owned());
+        }
+
+
+        let d = Array::from_shape_vec((nrows, ncols), c).unwrap().map(|x| (*x).clone());
+        match axis {
+            UtahAxis::Row => {
+                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
+                Ok(df)
+            }
+            UtahAxis::Column => {
+                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
+                Ok(df)
+            }
+
+        }
+    }
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        let (mut ncols, mut nrows) = match axis {
+            UtahAxis::Row => (other.len(), 0),
+            UtahAxis::Column => (0, other.len()),
+        };
+
+        for (i, j) in self {
+            match axis {
+                UtahAxis::Row => nrows += 1,
+                UtahAxis::Column => ncols += 1,
+            };
+
+            c.extend(j);
+            n.push(i.to_owned());
+        }
+
+        Ok(Array::from_shape_vec((nrows, ncols), c).unwrap().map(|x| (*x).clone()))
+
+
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+        let mut c = Vec::new();
+        for (_, j) in self {
+            c.extend(j);
+        }
+        Ok(Array::from_vec(c).map(|x| (*x).clone()))
+    }
+}
+
+impl<'a, I, T, L> ToDataFrame<'a, WindowMut<'a, T, L>, T, L> for Impute<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        let (mut ncols, mut nrows) = match axis {
+            UtahAxis::Row => (other.len(), 0),
+            UtahAxis::Column => (0, other.len()),
+        };
+
+        for (i, j) in self {
+            match axis {
+                UtahAxis::Row => nrows += 1,
+                UtahAxis::Column => ncols += 1,
+            };
+            c.extend(j);
+            n.push(i.to_owned());
+        }
+
+
+        let d = Array::from_shape_vec((nrows, ncols), c).unwrap().map(|x| (*x).clone());
+        match axis {
+            UtahAxis::Row => {
+                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
+                Ok(df)
+            }
+            UtahAxis::Column => {
+                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
+                Ok(df)
+            }
+
+        }
+    }
+
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+        let (mut ncols, mut nrows) = match axis {
+            UtahAxis::Row => (other.len(), 0),
+            UtahAxis::Column => (0, other.len()),
+        };
+
+        for (i, j) in self {
+            match axis {
+                UtahAxis::Row => nrows += 1,
+                UtahAxis::Column => ncols += 1,
+            };
+
+            c.extend(j);
+            n.push(i.to_owned());
+        }
+
+        Ok(Array::from_shape_vec((nrows, ncols), c).unwrap().map(|x| ((*x).clone())))
     }
 
     fn as_array(self) -> Result<Row<T>> {
@@ -459,12 +1184,13 @@ impl<'a, I, T> ToDataFrame<'a, WindowMut<'a, T>, T> for Impute<'a, I, T>
 
 
 
-impl<'a, I, T, F> ToDataFrame<'a, WindowMut<'a, T>, T> for MapDF<'a, T, I, F>
-    where I: Iterator<Item = WindowMut<'a, T>>,
+impl<'a, I, T, F, L> ToDataFrame<'a, WindowMut<'a, T, L>, T, L> for MapDF<'a, T, I, F, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
           T: UtahNum,
-          F: Fn(T) -> T
+          F: Fn(T) -> T,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn as_df(self) -> Result<DataFrame<T, L>> {
         let axis = self.axis.clone();
         let other = self.other.clone();
         let mut c = Vec::new();
@@ -529,3 +1255,235 @@ impl<'a, I, T, F> ToDataFrame<'a, WindowMut<'a, T>, T> for MapDF<'a, T, I, F>
         Ok(Array::from_vec(c).map(|x| ((*x).clone())))
     }
 }
+
+
+impl<'a, I, T, L> ToDataFrame<'a, WindowMut<'a, T, L>, T, L> for CumSum<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+
+        for (i, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+            n.push(i.to_owned());
+        }
+
+        let d = Array::from_shape_vec((n.len(), other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => {
+                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
+                Ok(df)
+            }
+            UtahAxis::Column => {
+                let df = DataFrame::new(d.reversed_axes()).columns(&n[..])?.index(&other[..])?;
+                Ok(df)
+            }
+
+        }
+    }
+
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = 0;
+
+        for (_, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+            n += 1;
+        }
+
+        let d = Array::from_shape_vec((n, other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => Ok(d),
+            UtahAxis::Column => Ok(d.reversed_axes()),
+        }
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+        let mut c = Vec::new();
+        for (_, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+        }
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, WindowMut<'a, T, L>, T, L> for CumProd<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+
+        for (i, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+            n.push(i.to_owned());
+        }
+
+        let d = Array::from_shape_vec((n.len(), other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => {
+                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
+                Ok(df)
+            }
+            UtahAxis::Column => {
+                let df = DataFrame::new(d.reversed_axes()).columns(&n[..])?.index(&other[..])?;
+                Ok(df)
+            }
+
+        }
+    }
+
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = 0;
+
+        for (_, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+            n += 1;
+        }
+
+        let d = Array::from_shape_vec((n, other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => Ok(d),
+            UtahAxis::Column => Ok(d.reversed_axes()),
+        }
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+        let mut c = Vec::new();
+        for (_, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+        }
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, WindowMut<'a, T, L>, T, L> for CumMax<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+
+        for (i, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+            n.push(i.to_owned());
+        }
+
+        let d = Array::from_shape_vec((n.len(), other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => {
+                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
+                Ok(df)
+            }
+            UtahAxis::Column => {
+                let df = DataFrame::new(d.reversed_axes()).columns(&n[..])?.index(&other[..])?;
+                Ok(df)
+            }
+
+        }
+    }
+
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = 0;
+
+        for (_, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+            n += 1;
+        }
+
+        let d = Array::from_shape_vec((n, other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => Ok(d),
+            UtahAxis::Column => Ok(d.reversed_axes()),
+        }
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+        let mut c = Vec::new();
+        for (_, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+        }
+        Ok(Array::from_vec(c))
+    }
+}
+
+
+impl<'a, I, T, L> ToDataFrame<'a, WindowMut<'a, T, L>, T, L> for CumMin<'a, I, T, L>
+    where I: Iterator<Item = WindowMut<'a, T, L>>,
+          T: UtahNum + PartialOrd,
+          L: UtahLabel
+{
+    fn as_df(self) -> Result<DataFrame<T, L>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = Vec::new();
+
+        for (i, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+            n.push(i.to_owned());
+        }
+
+        let d = Array::from_shape_vec((n.len(), other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => {
+                let df = DataFrame::new(d).columns(&other[..])?.index(&n[..])?;
+                Ok(df)
+            }
+            UtahAxis::Column => {
+                let df = DataFrame::new(d.reversed_axes()).columns(&n[..])?.index(&other[..])?;
+                Ok(df)
+            }
+
+        }
+    }
+
+    fn as_matrix(self) -> Result<Matrix<T>> {
+        let axis = self.axis;
+        let other = self.other.clone();
+        let mut c = Vec::new();
+        let mut n = 0;
+
+        for (_, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+            n += 1;
+        }
+
+        let d = Array::from_shape_vec((n, other.len()), c).unwrap();
+        match axis {
+            UtahAxis::Row => Ok(d),
+            UtahAxis::Column => Ok(d.reversed_axes()),
+        }
+    }
+
+    fn as_array(self) -> Result<Row<T>> {
+        let mut c = Vec::new();
+        for (_, j) in self {
+            c.extend(j.iter().map(|x| (*x).clone()));
+        }
+        Ok(Array::from_vec(c))
+    }
+}
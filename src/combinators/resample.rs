@@ -0,0 +1,187 @@
+//! Utah resampling combinator for dataframes with a datetime index.
+
+use util::types::*;
+use util::traits::*;
+use util::error::*;
+use dataframe::*;
+use datetime::{Datetime, TimeDelta};
+use mixedtypes::OuterType;
+use implement::impl_reshape::aggregate;
+use ndarray::{Array, Array2, Axis};
+use num::traits::{FromPrimitive, ToPrimitive};
+
+/// The most buckets that a resampling grid may hold.
+pub const MAX_BUCKETS: i64 = 1 << 24;
+
+/// A dataframe regrouped on a regular grid of datetimes, `freq` apart.
+///
+/// The grid runs from the bucket holding the earliest row to the bucket holding the latest, and
+/// every result is indexed by bucket start. Downsampling reduces the rows of each bucket with
+/// `agg`; upsampling fills each grid time from the rows around it with `asfreq`, `ffill`,
+/// `bfill` or `interpolate`.
+///
+/// The grid is allocated in full, so a grid of more than `MAX_BUCKETS` buckets, as from a fine
+/// `freq` over a long span of time, is an error.
+#[derive(Clone, Debug)]
+pub struct Resampler<'a, T: 'a>
+    where T: UtahNum
+{
+    df: &'a DataFrame<T, OuterType>,
+    freq: TimeDelta,
+}
+
+impl<'a, T> Resampler<'a, T>
+    where T: UtahNum + 'a
+{
+    pub fn new(df: &'a DataFrame<T, OuterType>, freq: TimeDelta) -> Resampler<'a, T> {
+        Resampler { df, freq }
+    }
+
+    /// Reduce the non-empty entries of each bucket with `aggfunc`, column by column. Buckets
+    /// without any are left empty.
+    pub fn agg(&self, aggfunc: AggFunc) -> Result<DataFrame<T, OuterType>>
+        where T: PartialOrd
+    {
+        let (positions, grid) = self.buckets()?;
+        let ncols = self.df.columns.len();
+        let mut groups: Vec<Vec<Vec<T>>> = vec![vec![Vec::new(); ncols]; grid.len()];
+        for (row, &b) in self.df.data.outer_iter().zip(positions.iter()) {
+            for (j, x) in row.iter().enumerate().filter(|&(_, x)| !x.is_empty()) {
+                groups[b][j].push(x.clone());
+            }
+        }
+        let c: Vec<T> = groups.into_iter()
+            .flat_map(|cells| cells.into_iter())
+            .map(|entries| if entries.is_empty() {
+                T::empty()
+            } else {
+                aggregate(entries, aggfunc)
+            })
+            .collect();
+        self.assemble(&grid[..], c)
+    }
+
+    /// The row at each grid time, or empty entries where there is none. If several rows share
+    /// a grid time, the last one is kept.
+    pub fn asfreq(&self) -> Result<DataFrame<T, OuterType>> {
+        let grid = self.buckets()?.1;
+        let dates = self.df.datetime_index()?;
+        let mut rows: Vec<Option<usize>> = vec![None; grid.len()];
+        for (i, d) in dates.iter().enumerate() {
+            if let Ok(k) = grid.binary_search(d) {
+                rows[k] = Some(i);
+            }
+        }
+        let ncols = self.df.columns.len();
+        let mut c = Vec::with_capacity(grid.len() * ncols);
+        for r in rows {
+            match r {
+                Some(i) => c.extend(self.df.data.row(i).iter().cloned()),
+                None => c.extend((0..ncols).map(|_| T::empty())),
+            }
+        }
+        self.assemble(&grid[..], c)
+    }
+
+    /// Fill each grid time with the last non-empty entry of each column at or before it.
+    pub fn ffill(&self) -> Result<DataFrame<T, OuterType>> {
+        self.fill(|obs, t| {
+            let k = match obs.binary_search_by(|&(d, _)| d.cmp(&t)) {
+                Ok(k) => Some(k),
+                Err(0) => None,
+                Err(k) => Some(k - 1),
+            };
+            k.map(|k| obs[k].1.clone()).unwrap_or_else(T::empty)
+        })
+    }
+
+    /// Fill each grid time with the next non-empty entry of each column at or after it.
+    pub fn bfill(&self) -> Result<DataFrame<T, OuterType>> {
+        self.fill(|obs, t| {
+            let k = match obs.binary_search_by(|&(d, _)| d.cmp(&t)) {
+                Ok(k) | Err(k) => k,
+            };
+            obs.get(k).map(|x| x.1.clone()).unwrap_or_else(T::empty)
+        })
+    }
+
+    /// Bucket position of each row, and the start of every bucket from the first to the last.
+    fn buckets(&self) -> Result<(Vec<usize>, Vec<Datetime>)> {
+        let starts: Vec<Datetime> = self.df
+            .datetime_index()?
+            .iter()
+            .map(|d| d.floor(self.freq))
+            .collect();
+        let (first, last) = match (starts.iter().min(), starts.iter().max()) {
+            (Some(&first), Some(&last)) => (first, last),
+            _ => return Ok((Vec::new(), Vec::new())),
+        };
+        let step = self.freq.seconds();
+        let positions = starts.iter()
+            .map(|d| ((d.timestamp() - first.timestamp()) / step) as usize)
+            .collect();
+        let n = (last.timestamp() - first.timestamp()) / step + 1;
+        if n > MAX_BUCKETS {
+            return Err(ErrorKind::TooManyBuckets(n, MAX_BUCKETS).into());
+        }
+        let grid = (0..n as usize).map(|k| first + TimeDelta::from_seconds(k as i64 * step)).collect();
+        Ok((positions, grid))
+    }
+
+    /// Fill the grid column by column, from the non-empty entries of each column sorted by
+    /// time.
+    fn fill<F>(&self, f: F) -> Result<DataFrame<T, OuterType>>
+        where F: Fn(&[(Datetime, T)], Datetime) -> T
+    {
+        let grid = self.buckets()?.1;
+        let dates = self.df.datetime_index()?;
+        let ncols = self.df.columns.len();
+        let mut out: Array2<T> = Array::from_elem((grid.len(), ncols), T::empty());
+        for (j, col) in self.df.data.axis_iter(Axis(1)).enumerate() {
+            let mut obs: Vec<(Datetime, T)> = dates.iter()
+                .cloned()
+                .zip(col.iter().cloned())
+                .filter(|(_, x)| !x.is_empty())
+                .collect();
+            obs.sort_by_key(|a| a.0);
+            for (k, &t) in grid.iter().enumerate() {
+                out[[k, j]] = f(&obs[..], t);
+            }
+        }
+        self.assemble(&grid[..], out.into_raw_vec())
+    }
+
+    fn assemble(&self, grid: &[Datetime], c: Vec<T>) -> Result<DataFrame<T, OuterType>> {
+        let d = Array::from_shape_vec((grid.len(), self.df.columns.len()), c).unwrap();
+        let index: Vec<OuterType> = grid.iter().map(|&d| OuterType::Datetime(d)).collect();
+        DataFrame::new(d).columns(&self.df.columns[..])?.index(&index[..])
+    }
+}
+
+impl<'a, T> Resampler<'a, T>
+    where T: UtahNum + ToPrimitive + FromPrimitive + 'a
+{
+    /// Fill each grid time by linear interpolation in time between the non-empty entries of
+    /// each column on either side of it. Grid times outside the observed range are left empty.
+    pub fn interpolate(&self) -> Result<DataFrame<T, OuterType>> {
+        self.fill(|obs, t| {
+            let k = match obs.binary_search_by(|&(d, _)| d.cmp(&t)) {
+                Ok(k) => return obs[k].1.clone(),
+                Err(k) => k,
+            };
+            if k == 0 || k == obs.len() {
+                return T::empty();
+            }
+            let (ref d0, ref x0) = obs[k - 1];
+            let (ref d1, ref x1) = obs[k];
+            match (x0.to_f64(), x1.to_f64()) {
+                (Some(a), Some(b)) => {
+                    let w = (t.timestamp() - d0.timestamp()) as f64 /
+                            (d1.timestamp() - d0.timestamp()) as f64;
+                    T::from_f64(a + w * (b - a)).unwrap_or_else(T::empty)
+                }
+                _ => T::empty(),
+            }
+        })
+    }
+}
@@ -0,0 +1,539 @@
+//! Utah rolling, expanding and exponentially weighted window combinators.
+
+use util::types::*;
+use util::traits::*;
+use util::error::*;
+use dataframe::*;
+use ndarray::{Array, ArrayView1, Axis};
+use num::traits::{FromPrimitive, ToPrimitive};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, VecDeque};
+
+/// A fixed-size window sliding down the index of a dataframe.
+///
+/// Each column is reduced independently. A result is only computed where the window holds at
+/// least `min_periods` non-empty entries; every other position is left empty.
+#[derive(Clone, Debug)]
+pub struct Rolling<'a, T: 'a, L = String>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    df: &'a DataFrame<T, L>,
+    window: usize,
+    min_periods: usize,
+    center: bool,
+}
+
+impl<'a, T, L> Rolling<'a, T, L>
+    where T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: &'a DataFrame<T, L>,
+               window: usize,
+               min_periods: usize,
+               center: bool)
+               -> Rolling<'a, T, L> {
+        Rolling {
+            df,
+            window,
+            min_periods,
+            center,
+        }
+    }
+
+    /// Rolling sum of non-empty entries.
+    pub fn sum(&self) -> Result<DataFrame<T, L>> {
+        self.slide(|col, out| {
+            let mut sum = T::zero();
+            let mut count = 0;
+            let mut prev = (0, 0);
+            for (i, (start, end)) in self.bounds(col.len()).into_iter().enumerate() {
+                for x in (prev.1..end).map(|j| &col[j]).filter(|x| !x.is_empty()) {
+                    sum = sum.clone() + x.clone();
+                    count += 1;
+                }
+                for x in (prev.0..start).map(|j| &col[j]).filter(|x| !x.is_empty()) {
+                    sum = sum.clone() - x.clone();
+                    count -= 1;
+                }
+                prev = (start, end);
+                if count >= self.min_periods {
+                    out[i] = sum.clone();
+                }
+            }
+        })
+    }
+
+    /// Rolling mean of non-empty entries.
+    pub fn mean(&self) -> Result<DataFrame<T, L>> {
+        self.slide(|col, out| {
+            let mut sum = T::zero();
+            let mut size = T::zero();
+            let mut count = 0;
+            let mut prev = (0, 0);
+            for (i, (start, end)) in self.bounds(col.len()).into_iter().enumerate() {
+                for x in (prev.1..end).map(|j| &col[j]).filter(|x| !x.is_empty()) {
+                    sum = sum.clone() + x.clone();
+                    size = size.clone() + T::one();
+                    count += 1;
+                }
+                for x in (prev.0..start).map(|j| &col[j]).filter(|x| !x.is_empty()) {
+                    sum = sum.clone() - x.clone();
+                    size = size.clone() - T::one();
+                    count -= 1;
+                }
+                prev = (start, end);
+                if count >= self.min_periods && count > 0 {
+                    out[i] = sum.clone() / size.clone();
+                }
+            }
+        })
+    }
+
+    /// Rolling sample standard deviation of non-empty entries.
+    pub fn std(&self) -> Result<DataFrame<T, L>>
+        where T: ToPrimitive + FromPrimitive
+    {
+        self.slide(|col, out| {
+            let (mut sum, mut squares) = (0f64, 0f64);
+            let mut count = 0;
+            let mut prev = (0, 0);
+            let values: Vec<Option<f64>> = col.iter()
+                .map(|x| if x.is_empty() { None } else { x.to_f64() })
+                .collect();
+            for (i, (start, end)) in self.bounds(col.len()).into_iter().enumerate() {
+                for x in values[prev.1..end].iter().filter_map(|x| *x) {
+                    sum += x;
+                    squares += x * x;
+                    count += 1;
+                }
+                for x in values[prev.0..start].iter().filter_map(|x| *x) {
+                    sum -= x;
+                    squares -= x * x;
+                    count -= 1;
+                }
+                prev = (start, end);
+                if count >= self.min_periods && count > 1 {
+                    let n = count as f64;
+                    let var = ((squares - sum * sum / n) / (n - 1.)).max(0.);
+                    out[i] = T::from_f64(var.sqrt()).unwrap_or(T::empty());
+                }
+            }
+        })
+    }
+
+    /// Rolling maximum of non-empty entries.
+    pub fn max(&self) -> Result<DataFrame<T, L>>
+        where T: PartialOrd
+    {
+        self.slide(|col, out| self.monotonic(col, out, Ordering::Greater))
+    }
+
+    /// Rolling minimum of non-empty entries.
+    pub fn min(&self) -> Result<DataFrame<T, L>>
+        where T: PartialOrd
+    {
+        self.slide(|col, out| self.monotonic(col, out, Ordering::Less))
+    }
+
+    /// Rolling median of non-empty entries.
+    pub fn median(&self) -> Result<DataFrame<T, L>>
+        where T: PartialOrd
+    {
+        self.slide(|col, out| {
+            // Keep the window sorted, so that each step is a binary search and a shift rather
+            // than a full sort.
+            let mut sorted: Vec<T> = Vec::with_capacity(self.window);
+            let mut prev = (0, 0);
+            for (i, (start, end)) in self.bounds(col.len()).into_iter().enumerate() {
+                for x in (prev.1..end).map(|j| &col[j]).filter(|x| !x.is_empty()) {
+                    let pos = match sorted.binary_search_by(|y| {
+                        y.partial_cmp(x).unwrap_or(Ordering::Equal)
+                    }) {
+                        Ok(pos) => pos,
+                        Err(pos) => pos,
+                    };
+                    sorted.insert(pos, x.clone());
+                }
+                for x in (prev.0..start).map(|j| &col[j]).filter(|x| !x.is_empty()) {
+                    let pos = match sorted.binary_search_by(|y| {
+                        y.partial_cmp(x).unwrap_or(Ordering::Equal)
+                    }) {
+                        Ok(pos) => Some(pos),
+                        Err(_) => sorted.iter().position(|y| y == x),
+                    };
+                    if let Some(pos) = pos {
+                        sorted.remove(pos);
+                    }
+                }
+                prev = (start, end);
+                let n = sorted.len();
+                if n >= self.min_periods && n > 0 {
+                    out[i] = if n % 2 == 1 {
+                        sorted[n / 2].clone()
+                    } else {
+                        (sorted[n / 2 - 1].clone() + sorted[n / 2].clone()) /
+                        (T::one() + T::one())
+                    };
+                }
+            }
+        })
+    }
+
+    /// Apply `f` to the non-empty entries of every window.
+    pub fn apply<F>(&self, f: F) -> Result<DataFrame<T, L>>
+        where F: Fn(&[T]) -> T
+    {
+        self.slide(|col, out| {
+            for (i, (start, end)) in self.bounds(col.len()).into_iter().enumerate() {
+                let values: Vec<T> = (start..end)
+                    .map(|j| &col[j])
+                    .filter(|x| !x.is_empty())
+                    .cloned()
+                    .collect();
+                if values.len() >= self.min_periods {
+                    out[i] = f(&values[..]);
+                }
+            }
+        })
+    }
+
+    /// The half-open range of rows that the window ending (or centered) at each row covers.
+    fn bounds(&self, n: usize) -> Vec<(usize, usize)> {
+        let offset = if self.center { (self.window - 1) / 2 } else { 0 };
+        (0..n)
+            .map(|i| {
+                let end = ::std::cmp::min(i + 1 + offset, n);
+                let start = (i + 1 + offset).saturating_sub(self.window);
+                (start, end)
+            })
+            .collect()
+    }
+
+    /// Track the extreme of each window with a monotonic deque of row positions, so that every
+    /// entry is pushed and popped at most once.
+    fn monotonic(&self, col: ArrayView1<T>, out: &mut [T], order: Ordering)
+        where T: PartialOrd
+    {
+        let counts = nonempty_counts(&col);
+        let mut deque: VecDeque<usize> = VecDeque::new();
+        let mut prev_end = 0;
+        for (i, (start, end)) in self.bounds(col.len()).into_iter().enumerate() {
+            for j in prev_end..end {
+                if col[j].is_empty() {
+                    continue;
+                }
+                while let Some(&k) = deque.back() {
+                    if col[j].partial_cmp(&col[k]) == Some(order) ||
+                       col[j].partial_cmp(&col[k]) == Some(Ordering::Equal) {
+                        deque.pop_back();
+                    } else {
+                        break;
+                    }
+                }
+                deque.push_back(j);
+            }
+            prev_end = end;
+            while let Some(&k) = deque.front() {
+                if k < start {
+                    deque.pop_front();
+                } else {
+                    break;
+                }
+            }
+            if counts[end] - counts[start] >= self.min_periods {
+                if let Some(&k) = deque.front() {
+                    out[i] = col[k].clone();
+                }
+            }
+        }
+    }
+
+    /// Run `f` over every column, collecting the results into a dataframe shaped like the
+    /// original.
+    fn slide<F>(&self, f: F) -> Result<DataFrame<T, L>>
+        where F: Fn(ArrayView1<T>, &mut [T])
+    {
+        if self.window == 0 {
+            return Err(ErrorKind::InvalidWindow("window size must be positive".into()).into());
+        }
+        if self.min_periods > self.window {
+            return Err(ErrorKind::InvalidWindow(format!("min_periods {} must not exceed \
+                                                         window size {}",
+                                                        self.min_periods,
+                                                        self.window))
+                .into());
+        }
+        let (nrows, ncols) = self.df.data.dim();
+        let mut data = Array::from_elem((nrows, ncols), T::empty());
+        for (j, col) in self.df.data.axis_iter(Axis(1)).enumerate() {
+            let mut out = vec![T::empty(); nrows];
+            f(col, &mut out);
+            for (i, x) in out.into_iter().enumerate() {
+                data[[i, j]] = x;
+            }
+        }
+        DataFrame::new(data).columns(&self.df.columns[..])?.index(&self.df.index[..])
+    }
+}
+
+/// A window anchored at the first row that grows by one row at a time.
+///
+/// A result is only computed where the window holds at least `min_periods` non-empty entries;
+/// every other position is left empty.
+#[derive(Clone, Debug)]
+pub struct Expanding<'a, T: 'a, L = String>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    df: &'a DataFrame<T, L>,
+    min_periods: usize,
+}
+
+impl<'a, T, L> Expanding<'a, T, L>
+    where T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: &'a DataFrame<T, L>, min_periods: usize) -> Expanding<'a, T, L> {
+        Expanding { df, min_periods }
+    }
+
+    /// Expanding sum of non-empty entries.
+    pub fn sum(&self) -> Result<DataFrame<T, L>> {
+        self.window().sum()
+    }
+
+    /// Expanding mean of non-empty entries.
+    pub fn mean(&self) -> Result<DataFrame<T, L>> {
+        self.window().mean()
+    }
+
+    /// Expanding sample standard deviation of non-empty entries.
+    pub fn std(&self) -> Result<DataFrame<T, L>>
+        where T: ToPrimitive + FromPrimitive
+    {
+        self.window().std()
+    }
+
+    /// Expanding maximum of non-empty entries.
+    pub fn max(&self) -> Result<DataFrame<T, L>>
+        where T: PartialOrd
+    {
+        self.window().max()
+    }
+
+    /// Expanding minimum of non-empty entries.
+    pub fn min(&self) -> Result<DataFrame<T, L>>
+        where T: PartialOrd
+    {
+        self.window().min()
+    }
+
+    /// Expanding median of non-empty entries.
+    pub fn median(&self) -> Result<DataFrame<T, L>>
+        where T: PartialOrd
+    {
+        // The window never drops entries, so keep the lower half of them in a max-heap and the
+        // upper half in a min-heap, rather than a sorted window that shifts on every insert.
+        self.window().slide(|col, out| {
+            let mut lower: BinaryHeap<Ordered<T>> = BinaryHeap::new();
+            let mut upper: BinaryHeap<Reverse<Ordered<T>>> = BinaryHeap::new();
+            for (i, x) in col.iter().enumerate() {
+                if !x.is_empty() {
+                    match lower.peek() {
+                        Some(top) if *x > top.0 => upper.push(Reverse(Ordered(x.clone()))),
+                        _ => lower.push(Ordered(x.clone())),
+                    }
+                    if lower.len() > upper.len() + 1 {
+                        let Ordered(y) = lower.pop().unwrap();
+                        upper.push(Reverse(Ordered(y)));
+                    } else if upper.len() > lower.len() {
+                        let Reverse(y) = upper.pop().unwrap();
+                        lower.push(y);
+                    }
+                }
+                let n = lower.len() + upper.len();
+                if n >= self.min_periods && n > 0 {
+                    let mid = lower.peek().unwrap().0.clone();
+                    out[i] = match upper.peek() {
+                        Some(&Reverse(Ordered(ref y))) if n % 2 == 0 => {
+                            (mid + y.clone()) / (T::one() + T::one())
+                        }
+                        _ => mid,
+                    };
+                }
+            }
+        })
+    }
+
+    /// Apply `f` to the non-empty entries of every window.
+    pub fn apply<F>(&self, f: F) -> Result<DataFrame<T, L>>
+        where F: Fn(&[T]) -> T
+    {
+        self.window().apply(f)
+    }
+
+    /// An expanding window is a rolling window that is never shorter than the frame.
+    fn window(&self) -> Rolling<'a, T, L> {
+        let window = *[self.df.data.rows(), self.min_periods, 1].iter().max().unwrap();
+        Rolling::new(self.df, window, self.min_periods, false)
+    }
+}
+
+/// An exponentially weighted window, in which the weight of each entry decays geometrically
+/// with its distance from the current row.
+///
+/// With `adjust` set, the weights are normalized over the entries seen so far; otherwise the
+/// estimate is updated recursively as `y = (1 - alpha) * y + alpha * x`. Empty entries do not
+/// contribute, but still count towards the distance of older entries, and carry the previous
+/// estimate forward.
+#[derive(Clone, Debug)]
+pub struct Ewm<'a, T: 'a, L = String>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    df: &'a DataFrame<T, L>,
+    decay: Decay,
+    adjust: bool,
+}
+
+impl<'a, T, L> Ewm<'a, T, L>
+    where T: UtahNum + 'a,
+          L: UtahLabel
+{
+    pub fn new(df: &'a DataFrame<T, L>, decay: Decay, adjust: bool) -> Ewm<'a, T, L> {
+        Ewm { df, decay, adjust }
+    }
+}
+
+impl<'a, T, L> Ewm<'a, T, L>
+    where T: UtahNum + ToPrimitive + FromPrimitive + 'a,
+          L: UtahLabel
+{
+    /// Exponentially weighted mean.
+    pub fn mean(&self) -> Result<DataFrame<T, L>> {
+        self.weigh(|mean, _| Some(mean))
+    }
+
+    /// Exponentially weighted variance, corrected for bias.
+    pub fn var(&self) -> Result<DataFrame<T, L>> {
+        self.weigh(|_, var| var)
+    }
+
+    /// Exponentially weighted standard deviation, corrected for bias.
+    pub fn std(&self) -> Result<DataFrame<T, L>> {
+        self.weigh(|_, var| var.map(|v| v.sqrt()))
+    }
+
+    /// The smoothing factor implied by the decay.
+    fn alpha(&self) -> Result<f64> {
+        let alpha = match self.decay {
+            Decay::Alpha(a) => a,
+            Decay::Span(s) if s >= 1. => 2. / (s + 1.),
+            Decay::HalfLife(h) if h > 0. => 1. - (0.5f64.ln() / h).exp(),
+            d => return Err(ErrorKind::InvalidWindow(format!("{:?}", d)).into()),
+        };
+        if alpha > 0. && alpha <= 1. {
+            Ok(alpha)
+        } else {
+            Err(ErrorKind::InvalidWindow(format!("{:?}", self.decay)).into())
+        }
+    }
+
+    /// Walk down every column, updating the weighted mean and variance one row at a time, and
+    /// collect `f(mean, variance)` into a dataframe shaped like the original.
+    fn weigh<F>(&self, f: F) -> Result<DataFrame<T, L>>
+        where F: Fn(f64, Option<f64>) -> Option<f64>
+    {
+        let alpha = self.alpha()?;
+        let new_weight = if self.adjust { 1. } else { alpha };
+        let (nrows, ncols) = self.df.data.dim();
+        let mut data = Array::from_elem((nrows, ncols), T::empty());
+        for (j, col) in self.df.data.axis_iter(Axis(1)).enumerate() {
+            // The running weighted mean and (biased) variance, the sum of the weights and of
+            // their squares, and the weight of all previous entries combined.
+            let mut state: Option<(f64, f64)> = None;
+            let (mut sum_weights, mut sum_squares, mut old_weight) = (0f64, 0f64, 0f64);
+            for (i, x) in col.iter().enumerate() {
+                let x = if x.is_empty() { None } else { x.to_f64() };
+                state = match (state, x) {
+                    (None, None) => None,
+                    (None, Some(x)) => {
+                        sum_weights = 1.;
+                        sum_squares = 1.;
+                        old_weight = 1.;
+                        Some((x, 0.))
+                    }
+                    (Some((mean, var)), x) => {
+                        sum_weights *= 1. - alpha;
+                        sum_squares *= (1. - alpha) * (1. - alpha);
+                        old_weight *= 1. - alpha;
+                        match x {
+                            None => Some((mean, var)),
+                            Some(x) => {
+                                let total = old_weight + new_weight;
+                                let next = (old_weight * mean + new_weight * x) / total;
+                                let var = (old_weight * (var + (mean - next) * (mean - next)) +
+                                           new_weight * (x - next) * (x - next)) /
+                                          total;
+                                sum_weights += new_weight;
+                                sum_squares += new_weight * new_weight;
+                                old_weight += new_weight;
+                                if !self.adjust {
+                                    sum_weights /= old_weight;
+                                    sum_squares /= old_weight * old_weight;
+                                    old_weight = 1.;
+                                }
+                                Some((next, var))
+                            }
+                        }
+                    }
+                };
+                if let Some((mean, var)) = state {
+                    let denominator = sum_weights * sum_weights - sum_squares;
+                    let unbiased = if denominator > 0. {
+                        Some(var * sum_weights * sum_weights / denominator)
+                    } else {
+                        None
+                    };
+                    if let Some(y) = f(mean, unbiased).and_then(T::from_f64) {
+                        data[[i, j]] = y;
+                    }
+                }
+            }
+        }
+        DataFrame::new(data).columns(&self.df.columns[..])?.index(&self.df.index[..])
+    }
+}
+
+/// An entry ordered by `partial_cmp`, with incomparable entries treated as equal, so that it
+/// can be kept in a heap.
+#[derive(Clone, Debug, PartialEq)]
+struct Ordered<T>(T);
+
+impl<T: PartialOrd> Eq for Ordered<T> {}
+
+impl<T: PartialOrd> PartialOrd for Ordered<T> {
+    fn partial_cmp(&self, other: &Ordered<T>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: PartialOrd> Ord for Ordered<T> {
+    fn cmp(&self, other: &Ordered<T>) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Running count of non-empty entries, such that `counts[j] - counts[i]` is the number of
+/// non-empty entries in rows `i..j`.
+fn nonempty_counts<T>(col: &ArrayView1<T>) -> Vec<usize>
+    where T: UtahNum
+{
+    let mut counts = vec![0];
+    for x in col.iter() {
+        let last = counts[counts.len() - 1];
+        counts.push(if x.is_empty() { last } else { last + 1 });
+    }
+    counts
+}
@@ -12,20 +12,22 @@ use util::error::*;
 
 
 #[derive(Clone, Debug)]
-pub struct Select<'a, I, T: 'a>
-    where I: Iterator<Item = Window<'a, T>>
+pub struct Select<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          L: UtahLabel
 {
     pub data: I,
-    pub ind: Vec<String>,
-    pub other: Vec<String>,
+    pub ind: Vec<L>,
+    pub other: Vec<L>,
     pub axis: UtahAxis,
 }
 
 
-impl<'a, I, T> Select<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>
+impl<'a, I, T, L> Select<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          L: UtahLabel
 {
-    pub fn new(df: I, ind: Vec<String>, other: Vec<String>, axis: UtahAxis) -> Select<'a, I, T> {
+    pub fn new(df: I, ind: Vec<L>, other: Vec<L>, axis: UtahAxis) -> Select<'a, I, T, L> {
 
         Select {
             data: df,
@@ -39,10 +41,11 @@ impl<'a, I, T> Select<'a, I, T>
 
 
 
-impl<'a, I, T> Iterator for Select<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>
+impl<'a, I, T, L> Iterator for Select<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          L: UtahLabel
 {
-    type Item = Window<'a, T>;
+    type Item = Window<'a, T, L>;
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             match self.data.next() {
@@ -62,20 +65,22 @@ impl<'a, I, T> Iterator for Select<'a, I, T>
 }
 
 #[derive(Clone, Debug)]
-pub struct Remove<'a, I, T: 'a>
-    where I: Iterator<Item = Window<'a, T>>
+pub struct Remove<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          L: UtahLabel
 {
     pub data: I,
-    pub ind: Vec<String>,
-    pub other: Vec<String>,
+    pub ind: Vec<L>,
+    pub other: Vec<L>,
     pub axis: UtahAxis,
 }
 
 
-impl<'a, I, T> Remove<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>
+impl<'a, I, T, L> Remove<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          L: UtahLabel
 {
-    pub fn new(df: I, ind: Vec<String>, other: Vec<String>, axis: UtahAxis) -> Remove<'a, I, T> {
+    pub fn new(df: I, ind: Vec<L>, other: Vec<L>, axis: UtahAxis) -> Remove<'a, I, T, L> {
 
         Remove {
             data: df,
@@ -88,10 +93,11 @@ impl<'a, I, T> Remove<'a, I, T>
 
 
 
-impl<'a, I, T> Iterator for Remove<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>
+impl<'a, I, T, L> Iterator for Remove<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          L: UtahLabel
 {
-    type Item = Window<'a, T>;
+    type Item = Window<'a, T, L>;
     fn next(&mut self) -> Option<Self::Item> {
         loop {
             match self.data.next() {
@@ -109,28 +115,30 @@ impl<'a, I, T> Iterator for Remove<'a, I, T>
 }
 
 #[derive(Clone)]
-pub struct Append<'a, I, T: 'a>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+pub struct Append<'a, I, T: 'a, L = String>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
     pub new_data: PutBack<I>,
-    pub other: Vec<String>,
+    pub other: Vec<L>,
     pub axis: UtahAxis,
 }
 
 
 
 
-impl<'a, I, T> Append<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+impl<'a, I, T, L> Append<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
     pub fn new(df: I,
-               name: String,
+               name: L,
                data: ArrayView1<'a, T>,
-               other: Vec<String>,
+               other: Vec<L>,
                axis: UtahAxis)
-               -> Append<'a, I, T> {
+               -> Append<'a, I, T, L> {
         let mut it = put_back(df);
         it.put_back((name, data));
         Append {
@@ -143,11 +151,12 @@ impl<'a, I, T> Append<'a, I, T>
 
 
 
-impl<'a, I, T> Iterator for Append<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>>,
-          T: UtahNum
+impl<'a, I, T, L> Iterator for Append<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>>,
+          T: UtahNum,
+          L: UtahLabel
 {
-    type Item = Window<'a, T>;
+    type Item = Window<'a, T, L>;
     fn next(&mut self) -> Option<Self::Item> {
         self.new_data.next()
     }
@@ -158,79 +167,146 @@ impl<'a, I, T> Iterator for Append<'a, I, T>
 
 
 
-impl<'a, T> Aggregate<'a, T> for DataFrameIterator<'a, T>
-    where T: UtahNum + 'a
+impl<'a, T, L> Aggregate<'a, T, L> for DataFrameIterator<'a, T, L>
+    where T: UtahNum + 'a,
+          L: UtahLabel
 {
-    fn sumdf(self) -> Sum<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn sumdf(self) -> Sum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Sum::new(self, other, axis)
     }
 
-    fn maxdf(self) -> Max<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn maxdf(self) -> Max<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Max::new(self, other, axis)
     }
 
-    fn mindf(self) -> Min<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn mindf(self) -> Min<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Min::new(self, other, axis)
     }
 
-    fn mean(self) -> Mean<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn mean(self) -> Mean<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Mean::new(self, other, axis)
     }
+
+    fn countdf(self) -> Count<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Count::new(self, axis)
+    }
+
+    fn prod(self) -> Prod<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Prod::new(self, axis)
+    }
+
+    fn median(self) -> Median<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Median::new(self, axis)
+    }
+
+    fn quantile(self, q: f64, interpolation: Interpolation) -> Result<Quantile<'a, Self, T, L>>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Quantile::new(self, q, interpolation, axis)
+    }
+
+    fn var(self) -> Var<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Var::new(self, axis)
+    }
+
+    fn mode(self) -> Mode<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Mode::new(self, axis)
+    }
+
+    fn nunique(self) -> NUnique<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        NUnique::new(self, axis)
+    }
+
+    fn idxmax(self) -> IdxMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        IdxMax::new(self, other, axis)
+    }
+
+    fn idxmin(self) -> IdxMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        IdxMin::new(self, other, axis)
+    }
 }
 
 
-impl<'a, T> Transform<'a, T> for DataFrameIterator<'a, T>
-    where T: UtahNum
+impl<'a, T, L> Transform<'a, T, L> for DataFrameIterator<'a, T, L>
+    where T: UtahNum,
+          L: UtahLabel
 {
-    fn select<U: ?Sized>(self, names: &'a [&'a U]) -> Select<'a, Self, T>
-        where String: From<&'a U>,
+    fn select<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Select<'a, Self, T, L>
+        where L: From<U::Owned>,
               T: 'a
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
-        let names: Vec<String> = names.iter()
-            .map(|x| String::from(*x))
+        let names: Vec<L> = names.iter()
+            .map(|x| L::from((*x).to_owned()))
             .collect();
         Select::new(self, names, other, axis)
     }
 
 
-    fn remove<U: ?Sized>(self, names: &'a [&'a U]) -> Remove<'a, Self, T>
-        where String: From<&'a U>,
+    fn remove<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Remove<'a, Self, T, L>
+        where L: From<U::Owned>,
               T: 'a
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
-        let names: Vec<String> = names.iter()
-            .map(|x| String::from(*x))
+        let names: Vec<L> = names.iter()
+            .map(|x| L::from((*x).to_owned()))
             .collect();
         Remove::new(self, names, other, axis)
 
     }
 
-    fn append<U: ?Sized>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T>
-        where String: From<&'a U>,
+    fn append<U: ?Sized + ToOwned>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T, L>
+        where L: From<U::Owned>,
               T: 'a
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
-        let name = String::from(name);
+        let name = L::from(name.to_owned());
         Append::new(self, name, data, other, axis)
 
     }
@@ -241,79 +317,146 @@ impl<'a, T> Transform<'a, T> for DataFrameIterator<'a, T>
 
 
 
-impl<'a, I, T> Aggregate<'a, T> for Select<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum + 'a
+impl<'a, I, T, L> Aggregate<'a, T, L> for Select<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum + 'a,
+          L: UtahLabel
 {
-    fn sumdf(self) -> Sum<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone
+    fn sumdf(self) -> Sum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Sum::new(self, other, axis)
     }
 
-    fn maxdf(self) -> Max<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone
+    fn maxdf(self) -> Max<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Max::new(self, other, axis)
     }
 
-    fn mindf(self) -> Min<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone
+    fn mindf(self) -> Min<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Min::new(self, other, axis)
     }
 
-    fn mean(self) -> Mean<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone
+    fn mean(self) -> Mean<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Mean::new(self, other, axis)
     }
+
+    fn countdf(self) -> Count<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
+    {
+        let axis = self.axis;
+        Count::new(self, axis)
+    }
+
+    fn prod(self) -> Prod<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
+    {
+        let axis = self.axis;
+        Prod::new(self, axis)
+    }
+
+    fn median(self) -> Median<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
+    {
+        let axis = self.axis;
+        Median::new(self, axis)
+    }
+
+    fn quantile(self, q: f64, interpolation: Interpolation) -> Result<Quantile<'a, Self, T, L>>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
+    {
+        let axis = self.axis;
+        Quantile::new(self, q, interpolation, axis)
+    }
+
+    fn var(self) -> Var<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
+    {
+        let axis = self.axis;
+        Var::new(self, axis)
+    }
+
+    fn mode(self) -> Mode<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
+    {
+        let axis = self.axis;
+        Mode::new(self, axis)
+    }
+
+    fn nunique(self) -> NUnique<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
+    {
+        let axis = self.axis;
+        NUnique::new(self, axis)
+    }
+
+    fn idxmax(self) -> IdxMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        IdxMax::new(self, other, axis)
+    }
+
+    fn idxmin(self) -> IdxMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        IdxMin::new(self, other, axis)
+    }
 }
 
-impl<'a, I, T> Transform<'a, T> for Select<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum + Clone + Debug
+impl<'a, I, T, L> Transform<'a, T, L> for Select<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum + Clone + Debug,
+          L: UtahLabel
 {
-    fn select<U: ?Sized>(self, names: &'a [&'a U]) -> Select<'a, Self, T>
-        where String: From<&'a U>,
+    fn select<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Select<'a, Self, T, L>
+        where L: From<U::Owned>,
               T: 'a
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         let names = names.iter()
-            .map(|x| (String::from(*x)))
+            .map(|x| L::from((*x).to_owned()))
             .collect();
         Select::new(self, names, other.clone(), axis)
     }
 
 
-    fn remove<U: ?Sized>(self, names: &'a [&'a U]) -> Remove<'a, Self, T>
-        where String: From<&'a U>
+    fn remove<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Remove<'a, Self, T, L>
+        where L: From<U::Owned>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         let names = names.iter()
-            .map(|x| String::from(*x))
+            .map(|x| L::from((*x).to_owned()))
             .collect();
         Remove::new(self, names, other.clone(), axis)
 
     }
 
-    fn append<U: ?Sized>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T>
-        where String: From<&'a U>,
+    fn append<U: ?Sized + ToOwned>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T, L>
+        where L: From<U::Owned>,
               T: 'a
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
-        let name = String::from(name);
+        let name = L::from(name.to_owned());
         Append::new(self, name, data, other, axis)
 
     }
@@ -323,77 +466,144 @@ impl<'a, I, T> Transform<'a, T> for Select<'a, I, T>
 
 
 
-impl<'a, I, T> Aggregate<'a, T> for Remove<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum
+impl<'a, I, T, L> Aggregate<'a, T, L> for Remove<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
 {
-    fn sumdf(self) -> Sum<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn sumdf(self) -> Sum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Sum::new(self, other, axis)
     }
 
-    fn maxdf(self) -> Max<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn maxdf(self) -> Max<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Max::new(self, other, axis)
     }
 
-    fn mindf(self) -> Min<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn mindf(self) -> Min<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Min::new(self, other, axis)
     }
 
-    fn mean(self) -> Mean<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn mean(self) -> Mean<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Mean::new(self, other, axis)
     }
+
+    fn countdf(self) -> Count<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Count::new(self, axis)
+    }
+
+    fn prod(self) -> Prod<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Prod::new(self, axis)
+    }
+
+    fn median(self) -> Median<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Median::new(self, axis)
+    }
+
+    fn quantile(self, q: f64, interpolation: Interpolation) -> Result<Quantile<'a, Self, T, L>>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Quantile::new(self, q, interpolation, axis)
+    }
+
+    fn var(self) -> Var<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Var::new(self, axis)
+    }
+
+    fn mode(self) -> Mode<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Mode::new(self, axis)
+    }
+
+    fn nunique(self) -> NUnique<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        NUnique::new(self, axis)
+    }
+
+    fn idxmax(self) -> IdxMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        IdxMax::new(self, other, axis)
+    }
+
+    fn idxmin(self) -> IdxMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        IdxMin::new(self, other, axis)
+    }
 }
 
-impl<'a, I, T> Transform<'a, T> for Remove<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum
+impl<'a, I, T, L> Transform<'a, T, L> for Remove<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
 {
-    fn select<U: ?Sized>(self, names: &'a [&'a U]) -> Select<'a, Self, T>
-        where String: From<&'a U>
+    fn select<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Select<'a, Self, T, L>
+        where L: From<U::Owned>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         let names = names.iter()
-            .map(|x| String::from(*x))
+            .map(|x| L::from((*x).to_owned()))
             .collect();
         Select::new(self, names, other, axis)
     }
 
 
-    fn remove<U: ?Sized>(self, names: &'a [&'a U]) -> Remove<'a, Self, T>
-        where String: From<&'a U>
+    fn remove<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Remove<'a, Self, T, L>
+        where L: From<U::Owned>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         let names = names.iter()
-            .map(|x| String::from(*x))
+            .map(|x| L::from((*x).to_owned()))
             .collect();
         Remove::new(self, names, other, axis)
 
     }
 
-    fn append<U: ?Sized>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T>
-        where String: From<&'a U>
+    fn append<U: ?Sized + ToOwned>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T, L>
+        where L: From<U::Owned>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
-        let name = String::from(name);
+        let name = L::from(name.to_owned());
         Append::new(self, name, data, other, axis)
 
     }
@@ -401,89 +611,157 @@ impl<'a, I, T> Transform<'a, T> for Remove<'a, I, T>
 
 
 
-impl<'a, I, T> Aggregate<'a, T> for Append<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum
+impl<'a, I, T, L> Aggregate<'a, T, L> for Append<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
 {
-    fn sumdf(self) -> Sum<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn sumdf(self) -> Sum<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Sum::new(self, other, axis)
     }
 
-    fn maxdf(self) -> Max<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn maxdf(self) -> Max<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Max::new(self, other, axis)
     }
 
-    fn mindf(self) -> Min<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn mindf(self) -> Min<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Min::new(self, other, axis)
     }
 
-    fn mean(self) -> Mean<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>>
+    fn mean(self) -> Mean<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         Mean::new(self, other, axis)
     }
+
+    fn countdf(self) -> Count<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Count::new(self, axis)
+    }
+
+    fn prod(self) -> Prod<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Prod::new(self, axis)
+    }
+
+    fn median(self) -> Median<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Median::new(self, axis)
+    }
+
+    fn quantile(self, q: f64, interpolation: Interpolation) -> Result<Quantile<'a, Self, T, L>>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Quantile::new(self, q, interpolation, axis)
+    }
+
+    fn var(self) -> Var<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Var::new(self, axis)
+    }
+
+    fn mode(self) -> Mode<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        Mode::new(self, axis)
+    }
+
+    fn nunique(self) -> NUnique<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let axis = self.axis;
+        NUnique::new(self, axis)
+    }
+
+    fn idxmax(self) -> IdxMax<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        IdxMax::new(self, other, axis)
+    }
+
+    fn idxmin(self) -> IdxMin<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>
+    {
+        let other = self.other.clone();
+        let axis = self.axis;
+        IdxMin::new(self, other, axis)
+    }
 }
-impl<'a, I, T> Transform<'a, T> for Append<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum
+impl<'a, I, T, L> Transform<'a, T, L> for Append<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
 {
-    fn select<U: ?Sized>(self, names: &'a [&'a U]) -> Select<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone,
-              String: From<&'a U>
+    fn select<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Select<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone,
+              L: From<U::Owned>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         let names = names.iter()
-            .map(|x| String::from(*x))
+            .map(|x| L::from((*x).to_owned()))
             .collect();
         Select::new(self, names, other, axis)
     }
 
 
-    fn remove<U: ?Sized>(self, names: &'a [&'a U]) -> Remove<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone,
-              String: From<&'a U>
+    fn remove<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Remove<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone,
+              L: From<U::Owned>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
         let names = names.iter()
-            .map(|x| String::from(*x))
+            .map(|x| L::from((*x).to_owned()))
             .collect();
         Remove::new(self, names, other, axis)
 
     }
 
-    fn append<U: ?Sized>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone,
-              String: From<&'a U>
+    fn append<U: ?Sized + ToOwned>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone,
+              L: From<U::Owned>
     {
         let other = self.other.clone();
         let axis = self.axis.clone();
-        let name = String::from(name);
+        let name = L::from(name.to_owned());
         Append::new(self, name, data, other, axis)
 
     }
 }
 
-impl<'a, I, T> ToDataFrame<'a, Window<'a, T>, T> for Remove<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum
+impl<'a, I, T, L> ToDataFrame<'a, Window<'a, T, L>, T, L> for Remove<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn as_df(self) -> Result<DataFrame<T, L>> {
         let s = self.clone();
         let other = self.other.clone();
         let axis = self.axis.clone();
@@ -543,11 +821,12 @@ impl<'a, I, T> ToDataFrame<'a, Window<'a, T>, T> for Remove<'a, I, T>
 
 
 
-impl<'a, I, T> ToDataFrame<'a, Window<'a, T>, T> for Append<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum
+impl<'a, I, T, L> ToDataFrame<'a, Window<'a, T, L>, T, L> for Append<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn as_df(self) -> Result<DataFrame<T, L>> {
         let s = self.clone();
         let other = self.other.clone();
         let axis = self.axis.clone();
@@ -611,11 +890,12 @@ impl<'a, I, T> ToDataFrame<'a, Window<'a, T>, T> for Append<'a, I, T>
 }
 
 
-impl<'a, I, T> ToDataFrame<'a, Window<'a, T>, T> for Select<'a, I, T>
-    where I: Iterator<Item = Window<'a, T>> + Clone,
-          T: UtahNum
+impl<'a, I, T, L> ToDataFrame<'a, Window<'a, T, L>, T, L> for Select<'a, I, T, L>
+    where I: Iterator<Item = Window<'a, T, L>> + Clone,
+          T: UtahNum,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn as_df(self) -> Result<DataFrame<T, L>> {
         let s = self.clone();
         let other = self.other.clone();
         let axis = self.axis.clone();
@@ -676,10 +956,11 @@ impl<'a, I, T> ToDataFrame<'a, Window<'a, T>, T> for Select<'a, I, T>
 }
 
 
-impl<'a, T> ToDataFrame<'a, Window<'a, T>, T> for DataFrameIterator<'a, T>
-    where T: UtahNum
+impl<'a, T, L> ToDataFrame<'a, Window<'a, T, L>, T, L> for DataFrameIterator<'a, T, L>
+    where T: UtahNum,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> {
+    fn as_df(self) -> Result<DataFrame<T, L>> {
         let s = self.clone();
         let other = self.other.clone();
         let axis = self.axis.clone();
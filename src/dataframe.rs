@@ -8,45 +8,50 @@ use util::traits::*;
 use std::slice::Iter;
 use ndarray::{ArrayView1, ArrayViewMut1, Dim, Ix};
 
-/// A read-only dataframe.
+/// A read-only dataframe. Rows and columns are labelled with any `UtahLabel`, strings by
+/// default.
 #[derive(Debug, Clone, PartialEq)]
-pub struct DataFrame<T>
-    where T: UtahNum
+pub struct DataFrame<T, L = String>
+    where T: UtahNum,
+          L: UtahLabel
 {
-    pub columns: Vec<String>,
+    pub columns: Vec<L>,
     pub data: Matrix<T>,
-    pub index: Vec<String>,
+    pub index: Vec<L>,
 }
 
 /// A read-write dataframe
 #[derive(Debug, PartialEq)]
-pub struct DataFrameMut<'a, T: 'a>
-    where T: UtahNum
+pub struct DataFrameMut<'a, T: 'a, L = String>
+    where T: UtahNum,
+          L: UtahLabel
 {
-    pub columns: Vec<String>,
+    pub columns: Vec<L>,
     pub data: MatrixMut<'a, T>,
-    pub index: Vec<String>,
+    pub index: Vec<L>,
 }
 
 
 /// The read-only dataframe iterator
 #[derive(Clone)]
-pub struct DataFrameIterator<'a, T: 'a>
-    where T: UtahNum
+pub struct DataFrameIterator<'a, T: 'a, L: 'a = String>
+    where T: UtahNum,
+          L: UtahLabel
 {
-    pub names: Iter<'a, String>,
+    pub names: Iter<'a, L>,
     pub data: AxisIter<'a, T, Dim<[Ix; 1]>>,
-    pub other: Vec<String>,
+    pub other: Vec<L>,
     pub axis: UtahAxis,
 }
 
 
 
 
-impl<'a, T> Iterator for DataFrameIterator<'a, T>
-    where T: UtahNum
+impl<'a, T, L> Iterator for DataFrameIterator<'a, T, L>
+    where T: UtahNum,
+          L: UtahLabel
 {
-    type Item = (String, ArrayView1<'a, T>);
+    type Item = (L, ArrayView1<'a, T>);
     fn next(&mut self) -> Option<Self::Item> {
         match self.names.next() {
             Some(val) => {
@@ -61,20 +66,22 @@ impl<'a, T> Iterator for DataFrameIterator<'a, T>
 }
 
 /// The read-write dataframe iterator
-pub struct DataFrameMutIterator<'a, T>
-    where T: UtahNum + 'a
+pub struct DataFrameMutIterator<'a, T, L: 'a = String>
+    where T: UtahNum + 'a,
+          L: UtahLabel
 {
-    pub names: Iter<'a, String>,
+    pub names: Iter<'a, L>,
     pub data: AxisIterMut<'a, T, Dim<[Ix; 1]>>,
-    pub other: Vec<String>,
+    pub other: Vec<L>,
     pub axis: UtahAxis,
 }
 
 
-impl<'a, T> Iterator for DataFrameMutIterator<'a, T>
-    where T: UtahNum
+impl<'a, T, L> Iterator for DataFrameMutIterator<'a, T, L>
+    where T: UtahNum,
+          L: UtahLabel
 {
-    type Item = (String, ArrayViewMut1<'a, T>);
+    type Item = (L, ArrayViewMut1<'a, T>);
 
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -91,11 +98,12 @@ impl<'a, T> Iterator for DataFrameMutIterator<'a, T>
 }
 
 
-impl<'a, T> DataFrameMut<'a, T>
-    where T: 'a + UtahNum
+impl<'a, T, L> DataFrameMut<'a, T, L>
+    where T: 'a + UtahNum,
+          L: UtahLabel
 {
     /// Dereference a mutable dataframe as an owned dataframe.
-    pub fn to_df(self) -> Result<DataFrame<T>> {
+    pub fn to_df(self) -> Result<DataFrame<T, L>> {
         let d = self.data.map(|x| ((*x).clone()));
         let df = DataFrame::new(d).columns(&self.columns[..])?.index(&self.index[..])?;
         Ok(df)
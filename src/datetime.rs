@@ -0,0 +1,452 @@
+//! Utah datetime support
+//!
+//! Datetimes are stored as seconds since the Unix epoch, together with a fixed offset from UTC
+//! that is only used to display them and to read their calendar fields. There is no timezone
+//! database, so daylight saving time and named zones are not supported.
+
+use util::error::*;
+use std::cmp::Ordering;
+use std::ops::Add;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::fmt;
+use std::result;
+use rustc_serialize::{Decodable, Decoder};
+
+const SECONDS_PER_DAY: i64 = 86400;
+
+/// Monday, 5 January 1970, the first Monday after the epoch. Buckets of regular frequencies are
+/// aligned to it, so that weekly buckets start on Mondays.
+const BUCKET_ORIGIN: i64 = 4 * SECONDS_PER_DAY;
+
+/// The formats tried, in order, when a datetime is parsed without an explicit format.
+const DEFAULT_FORMATS: [&str; 6] = ["%Y-%m-%dT%H:%M:%S%z",
+                                    "%Y-%m-%dT%H:%M:%S",
+                                    "%Y-%m-%d %H:%M:%S%z",
+                                    "%Y-%m-%d %H:%M:%S",
+                                    "%Y-%m-%d %H:%M",
+                                    "%Y-%m-%d"];
+
+/// A fixed span of time, written as a count and a unit: `s` for seconds, `min` for minutes, `h`
+/// for hours, `D` for days and `W` for weeks, as in `15min` or `1W`. Months and years are not
+/// fixed spans, so they are not supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimeDelta {
+    seconds: i64,
+}
+
+impl TimeDelta {
+    pub fn from_seconds(seconds: i64) -> TimeDelta {
+        TimeDelta { seconds }
+    }
+
+    pub fn seconds(&self) -> i64 {
+        self.seconds
+    }
+}
+
+impl FromStr for TimeDelta {
+    type Err = Error;
+    fn from_str(s: &str) -> Result<TimeDelta> {
+        let invalid = || -> Error {
+            ErrorKind::ParseError(format!("invalid frequency '{}'", s)).into()
+        };
+        let digits = s.chars().take_while(|c| c.is_ascii_digit()).count();
+        let n: i64 = if digits == 0 { 1 } else { s[..digits].parse().map_err(|_| invalid())? };
+        let unit = match &s[digits..] {
+            "s" | "S" => 1,
+            "min" | "T" => 60,
+            "h" | "H" => 3600,
+            "D" | "d" => SECONDS_PER_DAY,
+            "W" | "w" => 7 * SECONDS_PER_DAY,
+            _ => return Err(invalid()),
+        };
+        if n == 0 {
+            return Err(invalid());
+        }
+        n.checked_mul(unit).map(TimeDelta::from_seconds).ok_or_else(invalid)
+    }
+}
+
+/// An instant in time, shown at a fixed offset from UTC. Two datetimes are equal if they are
+/// the same instant, whatever their offsets.
+#[derive(Clone, Copy, Debug)]
+pub struct Datetime {
+    timestamp: i64,
+    offset: i32,
+}
+
+impl Datetime {
+    /// The UTC datetime `timestamp` seconds after the Unix epoch.
+    pub fn from_timestamp(timestamp: i64) -> Datetime {
+        Datetime {
+            timestamp,
+            offset: 0,
+        }
+    }
+
+    /// Midnight UTC on the given date.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Result<Datetime> {
+        Datetime::from_ymd_hms(year, month, day, 0, 0, 0)
+    }
+
+    /// The given date and time in UTC.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let d = Datetime::from_ymd_hms(2016, 1, 1, 12, 30, 0).unwrap();
+    /// assert_eq!(d.timestamp(), 1451651400);
+    /// assert!(Datetime::from_ymd(2016, 2, 30).is_err());
+    /// ```
+    pub fn from_ymd_hms(year: i32,
+                        month: u32,
+                        day: u32,
+                        hour: u32,
+                        minute: u32,
+                        second: u32)
+                        -> Result<Datetime> {
+        if !(1..=12).contains(&month) || day < 1 || day > days_in_month(year, month) ||
+           hour > 23 || minute > 59 || second > 59 {
+            return Err(ErrorKind::ParseError(format!("invalid datetime {:04}-{:02}-{:02} \
+                                                      {:02}:{:02}:{:02}",
+                                                     year,
+                                                     month,
+                                                     day,
+                                                     hour,
+                                                     minute,
+                                                     second))
+                .into());
+        }
+        let days = days_from_civil(year, month, day);
+        let seconds = (hour * 3600 + minute * 60 + second) as i64;
+        Ok(Datetime::from_timestamp(days * SECONDS_PER_DAY + seconds))
+    }
+
+    /// The same instant, shown at `offset` seconds east of UTC. Offsets of a day or more are
+    /// an error.
+    pub fn with_offset(self, offset: i32) -> Result<Datetime> {
+        if offset.abs() >= SECONDS_PER_DAY as i32 {
+            return Err(ErrorKind::InvalidOffset(offset).into());
+        }
+        Ok(Datetime {
+            timestamp: self.timestamp,
+            offset,
+        })
+    }
+
+    /// The start of the bucket of width `freq` that holds this datetime. Buckets are aligned to
+    /// local midnight on a Monday, so daily buckets start at midnight and weekly ones on Mondays.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let d: Datetime = "2016-01-07 15:45".parse().unwrap();
+    /// assert_eq!(d.floor("1h".parse().unwrap()), "2016-01-07 15:00".parse().unwrap());
+    /// assert_eq!(d.floor("1W".parse().unwrap()), "2016-01-04".parse().unwrap());
+    /// ```
+    pub fn floor(&self, freq: TimeDelta) -> Datetime {
+        let local = self.timestamp + self.offset as i64 - BUCKET_ORIGIN;
+        let start = floor_div(local, freq.seconds) * freq.seconds + BUCKET_ORIGIN;
+        Datetime {
+            timestamp: start - self.offset as i64,
+            offset: self.offset,
+        }
+    }
+
+    /// Seconds since the Unix epoch.
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// Seconds east of UTC.
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// Parse a datetime with a `strftime`-like format. The supported specifiers are `%Y`, `%m`,
+    /// `%d`, `%H`, `%M`, `%S`, `%z` (`Z`, `+HH:MM` or `+HHMM`) and `%%`; every other character
+    /// must match literally. Fields that are not in the format default to the epoch.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let d = Datetime::parse("03/01/2016 10:00 +01:00", "%d/%m/%Y %H:%M %z").unwrap();
+    /// assert_eq!((d.year(), d.month(), d.day(), d.hour()), (2016, 1, 3, 10));
+    /// assert_eq!(d, "2016-01-03 09:00".parse().unwrap());
+    /// ```
+    pub fn parse(s: &str, format: &str) -> Result<Datetime> {
+        let mismatch = || -> Error {
+            ErrorKind::ParseError(format!("'{}' does not match datetime format '{}'", s, format))
+                .into()
+        };
+        let (mut year, mut month, mut day) = (1970, 1, 1);
+        let (mut hour, mut minute, mut second, mut offset) = (0, 0, 0, 0);
+        let mut rest = s;
+        let mut spec = format.chars();
+        while let Some(c) = spec.next() {
+            if c != '%' {
+                if !rest.starts_with(c) {
+                    return Err(mismatch());
+                }
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+            match spec.next() {
+                Some('Y') => year = take_number(&mut rest, 4, true).ok_or_else(mismatch)? as i32,
+                Some('m') => month = take_number(&mut rest, 2, false).ok_or_else(mismatch)? as u32,
+                Some('d') => day = take_number(&mut rest, 2, false).ok_or_else(mismatch)? as u32,
+                Some('H') => hour = take_number(&mut rest, 2, false).ok_or_else(mismatch)? as u32,
+                Some('M') => minute = take_number(&mut rest, 2, false).ok_or_else(mismatch)? as u32,
+                Some('S') => second = take_number(&mut rest, 2, false).ok_or_else(mismatch)? as u32,
+                Some('z') => offset = take_offset(&mut rest).ok_or_else(mismatch)?,
+                Some('%') if rest.starts_with('%') => rest = &rest[1..],
+                Some('%') => return Err(mismatch()),
+                _ => {
+                    return Err(ErrorKind::ParseError(format!("invalid datetime format '{}'",
+                                                             format))
+                        .into())
+                }
+            }
+        }
+        if !rest.is_empty() {
+            return Err(mismatch());
+        }
+        let local = Datetime::from_ymd_hms(year, month, day, hour, minute, second)?;
+        Datetime::from_timestamp(local.timestamp - offset as i64).with_offset(offset)
+    }
+
+    /// Format the datetime with the specifiers understood by `parse`.
+    pub fn format(&self, format: &str) -> String {
+        let (year, month, day) = civil_from_days(self.days());
+        let mut s = String::new();
+        let mut spec = format.chars();
+        while let Some(c) = spec.next() {
+            if c != '%' {
+                s.push(c);
+                continue;
+            }
+            match spec.next() {
+                Some('Y') => s.push_str(&format!("{:04}", year)),
+                Some('m') => s.push_str(&format!("{:02}", month)),
+                Some('d') => s.push_str(&format!("{:02}", day)),
+                Some('H') => s.push_str(&format!("{:02}", self.hour())),
+                Some('M') => s.push_str(&format!("{:02}", self.minute())),
+                Some('S') => s.push_str(&format!("{:02}", self.second())),
+                Some('z') => {
+                    let sign = if self.offset < 0 { '-' } else { '+' };
+                    let offset = self.offset.abs();
+                    s.push_str(&format!("{}{:02}:{:02}", sign, offset / 3600, offset % 3600 / 60))
+                }
+                Some(x) => {
+                    s.push('%');
+                    s.push(x);
+                }
+                None => s.push('%'),
+            }
+        }
+        s
+    }
+
+    pub fn year(&self) -> i32 {
+        civil_from_days(self.days()).0
+    }
+
+    /// The month, from 1 for January to 12 for December.
+    pub fn month(&self) -> u32 {
+        civil_from_days(self.days()).1
+    }
+
+    pub fn day(&self) -> u32 {
+        civil_from_days(self.days()).2
+    }
+
+    pub fn hour(&self) -> u32 {
+        (self.seconds_of_day() / 3600) as u32
+    }
+
+    pub fn minute(&self) -> u32 {
+        (self.seconds_of_day() % 3600 / 60) as u32
+    }
+
+    pub fn second(&self) -> u32 {
+        (self.seconds_of_day() % 60) as u32
+    }
+
+    /// The day of the week, from 0 for Monday to 6 for Sunday.
+    pub fn weekday(&self) -> u32 {
+        // The epoch was a Thursday.
+        ((self.days() % 7 + 7 + 3) % 7) as u32
+    }
+
+    /// Days since the epoch, in local time.
+    fn days(&self) -> i64 {
+        floor_div(self.timestamp + self.offset as i64, SECONDS_PER_DAY)
+    }
+
+    /// Seconds since local midnight.
+    fn seconds_of_day(&self) -> i64 {
+        self.timestamp + self.offset as i64 - self.days() * SECONDS_PER_DAY
+    }
+}
+
+impl Add<TimeDelta> for Datetime {
+    type Output = Datetime;
+    fn add(self, rhs: TimeDelta) -> Datetime {
+        Datetime {
+            timestamp: self.timestamp + rhs.seconds,
+            offset: self.offset,
+        }
+    }
+}
+
+impl PartialEq for Datetime {
+    fn eq(&self, other: &Datetime) -> bool {
+        self.timestamp == other.timestamp
+    }
+}
+
+impl Eq for Datetime {}
+
+impl PartialOrd for Datetime {
+    fn partial_cmp(&self, other: &Datetime) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Datetime {
+    fn cmp(&self, other: &Datetime) -> Ordering {
+        self.timestamp.cmp(&other.timestamp)
+    }
+}
+
+impl Hash for Datetime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.timestamp.hash(state)
+    }
+}
+
+impl fmt::Display for Datetime {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.offset == 0 {
+            write!(f, "{}", self.format("%Y-%m-%d %H:%M:%S"))
+        } else {
+            write!(f, "{}", self.format("%Y-%m-%d %H:%M:%S%z"))
+        }
+    }
+}
+
+impl FromStr for Datetime {
+    type Err = Error;
+    /// Parse an ISO 8601 date or datetime, such as `2016-01-01`, `2016-01-01 12:00:00` or
+    /// `2016-01-01T12:00:00+01:00`.
+    fn from_str(s: &str) -> Result<Datetime> {
+        DEFAULT_FORMATS.iter()
+            .filter_map(|format| Datetime::parse(s, format).ok())
+            .next()
+            .ok_or_else(|| ErrorKind::ParseError(format!("invalid datetime '{}'", s)).into())
+    }
+}
+
+impl Decodable for Datetime {
+    fn decode<D: Decoder>(d: &mut D) -> result::Result<Datetime, D::Error> {
+        let s = d.read_str()?;
+        s.parse().map_err(|_| d.error(&format!("invalid datetime '{}'", s)))
+    }
+}
+
+/// Every datetime from `start` to `end`, inclusive, at steps of `freq`.
+///
+/// ```
+/// use utah::prelude::*;
+/// let index = date_range("2016-01-01", "2016-01-02", "6h").unwrap();
+/// assert_eq!(index.len(), 5);
+/// assert_eq!(index[1].to_string(), "2016-01-01 06:00:00");
+/// ```
+pub fn date_range(start: &str, end: &str, freq: &str) -> Result<Vec<Datetime>> {
+    let (start, end): (Datetime, Datetime) = (start.parse()?, end.parse()?);
+    let freq: TimeDelta = freq.parse()?;
+    let mut range = Vec::new();
+    let mut d = start;
+    while d <= end {
+        range.push(d);
+        d = d + freq;
+    }
+    Ok(range)
+}
+
+fn floor_div(a: i64, b: i64) -> i64 {
+    let q = a / b;
+    if a % b < 0 { q - 1 } else { q }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since the epoch of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year } as i64;
+    let era = floor_div(y, 400);
+    let yoe = y - era * 400;
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// The date that is `days` days after the epoch.
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let z = days + 719468;
+    let era = floor_div(z, 146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = (yoe + era * 400) as i32 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Consume a number of at most `max_digits` digits from the front of `s`.
+fn take_number(s: &mut &str, max_digits: usize, signed: bool) -> Option<i64> {
+    let negative = signed && s.starts_with('-');
+    let start = if negative { 1 } else { 0 };
+    let len = s[start..].chars().take(max_digits).take_while(|c| c.is_ascii_digit()).count();
+    if len == 0 {
+        return None;
+    }
+    let n: i64 = s[start..start + len].parse().ok()?;
+    *s = &s[start + len..];
+    Some(if negative { -n } else { n })
+}
+
+/// Consume a UTC offset (`Z`, `+HH:MM` or `+HHMM`) from the front of `s`, in seconds.
+fn take_offset(s: &mut &str) -> Option<i32> {
+    if s.starts_with('Z') {
+        *s = &s[1..];
+        return Some(0);
+    }
+    let sign = match s.chars().next() {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => return None,
+    };
+    *s = &s[1..];
+    let hours = take_number(s, 2, false)?;
+    if s.starts_with(':') {
+        *s = &s[1..];
+    }
+    let minutes = take_number(s, 2, false)?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(sign * (hours * 3600 + minutes * 60) as i32)
+}
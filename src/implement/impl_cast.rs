@@ -0,0 +1,206 @@
+use util::error::*;
+use util::traits::*;
+use util::types::*;
+use dataframe::*;
+use mixedtypes::{InnerType, DType};
+use implement::impl_duplicates::column_position;
+use ndarray::Array;
+use num::traits::NumCast;
+
+macro_rules! impl_numeric_cast {
+    ($($t:ty),*) => {
+        $(impl Cast<f64> for $t {
+            fn cast(&self) -> Option<f64> {
+                NumCast::from(*self)
+            }
+            fn cast_exact(&self) -> Option<f64> {
+                exact(*self)
+            }
+        }
+
+        impl Cast<f32> for $t {
+            fn cast(&self) -> Option<f32> {
+                NumCast::from(*self)
+            }
+            fn cast_exact(&self) -> Option<f32> {
+                exact(*self)
+            }
+        }
+
+        impl Cast<i64> for $t {
+            fn cast(&self) -> Option<i64> {
+                NumCast::from(*self)
+            }
+            fn cast_exact(&self) -> Option<i64> {
+                exact(*self)
+            }
+        }
+
+        impl Cast<i32> for $t {
+            fn cast(&self) -> Option<i32> {
+                NumCast::from(*self)
+            }
+            fn cast_exact(&self) -> Option<i32> {
+                exact(*self)
+            }
+        }
+
+        impl Cast<u64> for $t {
+            fn cast(&self) -> Option<u64> {
+                NumCast::from(*self)
+            }
+            fn cast_exact(&self) -> Option<u64> {
+                exact(*self)
+            }
+        }
+
+        impl Cast<InnerType> for $t {
+            fn cast(&self) -> Option<InnerType> {
+                Some(InnerType::from(*self))
+            }
+            fn cast_exact(&self) -> Option<InnerType> {
+                self.cast()
+            }
+        })*
+    }
+}
+
+impl_numeric_cast!(f64, f32, i64, i32, u64);
+
+macro_rules! impl_inner_cast {
+    ($($t:ty => $dtype:expr),*) => {
+        $(impl Cast<$t> for InnerType {
+            fn cast(&self) -> Option<$t> {
+                self.to_dtype($dtype).and_then(|x| NumCast::from(x))
+            }
+            fn cast_exact(&self) -> Option<$t> {
+                self.to_dtype_exact($dtype).and_then(|x| NumCast::from(x))
+            }
+        })*
+    }
+}
+
+impl_inner_cast!(f64 => DType::Float,
+                 f32 => DType::Float32,
+                 i64 => DType::Int64,
+                 i32 => DType::Int32,
+                 u64 => DType::UInt64);
+
+impl Cast<InnerType> for InnerType {
+    fn cast(&self) -> Option<InnerType> {
+        Some(self.clone())
+    }
+    fn cast_exact(&self) -> Option<InnerType> {
+        self.cast()
+    }
+}
+
+/// Cast `x` only if casting the result back gives `x` again.
+fn exact<T, U>(x: T) -> Option<U>
+    where T: NumCast + PartialEq + Copy,
+          U: NumCast + Copy
+{
+    NumCast::from(x).and_then(|y: U| if NumCast::from(y) == Some(x) { Some(y) } else { None })
+}
+
+impl<T, L> AsType<T, L> for DataFrame<T, L>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    /// Convert every entry to type `U`. In strict mode, an entry that can't be cast without
+    /// changing its value, such as a string that doesn't parse as a number or the float `1.5`
+    /// cast to an integer, is an error, and so is an empty entry if `U` has no empty value, as
+    /// `i64` and `u64` don't. In lossy mode, numbers are truncated or rounded to fit, and entries
+    /// that can't be cast at all become `U::empty()`, which is `0` for integers.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[InnerType::from("1.5"), InnerType::Int32(2)],
+    ///                [InnerType::Empty, InnerType::from("n/a")]]);
+    /// let df: DataFrame<InnerType> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// assert!(df.astype::<f64>(CastMode::Strict).is_err());
+    /// let res = df.astype::<f64>(CastMode::Lossy).unwrap();
+    /// assert_eq!(res.data.row(0), arr1(&[1.5, 2.0]));
+    /// assert!(res.data[[1, 1]].is_nan());
+    /// let empty: DataFrame<InnerType> = DataFrame::new(arr2(&[[InnerType::Empty]]));
+    /// assert!(empty.astype::<f64>(CastMode::Strict).unwrap().data[[0, 0]].is_nan());
+    /// assert!(empty.astype::<i64>(CastMode::Strict).is_err());
+    /// ```
+    fn astype<U>(&self, mode: CastMode) -> Result<DataFrame<U, L>>
+        where T: Cast<U>,
+              U: UtahNum
+    {
+        let mut c = Vec::with_capacity(self.data.len());
+        for row in self.data.outer_iter() {
+            for (x, column) in row.iter().zip(self.columns.iter()) {
+                c.push(cast_entry(x, mode, || column.to_string())?);
+            }
+        }
+        let data = Array::from_shape_vec(self.data.dim(), c).unwrap();
+        Ok(DataFrame {
+            columns: self.columns.clone(),
+            data,
+            index: self.index.clone(),
+        })
+    }
+}
+
+impl CastColumn for DataFrame<InnerType> {
+    /// Convert the entries of one column to `dtype`. Empty entries stay empty. In strict mode,
+    /// entries are cast with `InnerType::to_dtype_exact`, and those that can't be are an error;
+    /// in lossy mode, they are cast with `InnerType::to_dtype`, and those that can't be are left
+    /// empty.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[InnerType::from("7")], [InnerType::from("12")]]);
+    /// let df: DataFrame<InnerType> = DataFrame::new(a).columns(&["n"]).unwrap();
+    /// let res = df.cast_column("n", DType::Int64, CastMode::Strict).unwrap();
+    /// assert_eq!(res.data[[1, 0]], InnerType::Int64(12));
+    /// assert!(df.cast_column("n", DType::Bool, CastMode::Strict).is_err());
+    /// ```
+    fn cast_column(&self,
+                   column: &str,
+                   dtype: DType,
+                   mode: CastMode)
+                   -> Result<DataFrame<InnerType>> {
+        let j = column_position(self, column)?;
+        let mut df = self.clone();
+        for x in df.data.column_mut(j).iter_mut() {
+            if x.is_empty() {
+                continue;
+            }
+            let res = match mode {
+                CastMode::Strict => x.to_dtype_exact(dtype),
+                CastMode::Lossy => x.to_dtype(dtype),
+            };
+            *x = match (res, mode) {
+                (Some(y), _) => y,
+                (None, CastMode::Lossy) => InnerType::Empty,
+                (None, CastMode::Strict) => {
+                    return Err(ErrorKind::InvalidCast(format!("{:?}", x), column.to_string())
+                        .into())
+                }
+            };
+        }
+        Ok(df)
+    }
+}
+
+fn cast_entry<T, U, F>(x: &T, mode: CastMode, column: F) -> Result<U>
+    where T: UtahNum + Cast<U>,
+          U: UtahNum,
+          F: Fn() -> String
+{
+    let res = match mode {
+        CastMode::Strict => x.cast_exact(),
+        CastMode::Lossy => x.cast(),
+    };
+    match res {
+        Some(y) => Ok(y),
+        None if mode == CastMode::Lossy => Ok(U::empty()),
+        // An empty entry is only cast to another empty entry, never to a value such as `0i64`.
+        None if x.is_empty() && U::empty().is_empty() => Ok(U::empty()),
+        None => Err(ErrorKind::InvalidCast(format!("{:?}", x), column()).into()),
+    }
+}
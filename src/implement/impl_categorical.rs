@@ -0,0 +1,38 @@
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use categorical::Categorical;
+use mixedtypes::InnerType;
+use implement::impl_duplicates::column_position;
+use std::rc::Rc;
+
+impl ToCategorical for DataFrame<InnerType> {
+    /// Encode a column as a categorical, with its distinct values in sorted order as the
+    /// categories. Entries that are not strings are encoded by their labels, and empty entries
+    /// are left empty.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[InnerType::from("NY")], [InnerType::Empty], [InnerType::from("CA")]]);
+    /// let df: DataFrame<InnerType> = DataFrame::new(a).columns(&["state"]).unwrap();
+    /// let cat = df.to_categorical("state").unwrap();
+    /// assert_eq!(cat.codes(), &[Some(1), None, Some(0)]);
+    /// ```
+    fn to_categorical(&self, column: &str) -> Result<Categorical> {
+        let j = column_position(self, column)?;
+        let values: Vec<Option<String>> = self.data
+            .column(j)
+            .iter()
+            .map(|x| match *x {
+                InnerType::Str(ref s) => Some(s.clone()),
+                ref x => x.hash_key().to_label().map(|l| l.to_string()),
+            })
+            .collect();
+        let mut categories: Vec<String> = values.iter().filter_map(|x| x.clone()).collect();
+        categories.sort();
+        categories.dedup();
+        Ok(Categorical::encode(values.iter().map(|x| x.as_ref().map(|s| &s[..])),
+                               Rc::new(categories),
+                               false))
+    }
+}
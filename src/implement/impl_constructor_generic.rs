@@ -1,17 +1,21 @@
 use util::error::*;
 use util::types::*;
-use std::string::ToString;
 use std::iter::Iterator;
-use ndarray::Axis;
+use ndarray::{Array, Axis};
+use ndarray_rand::RandomExt;
+use rand::distributions::IndependentSample;
+use implement::impl_sample::seeded_rng;
 use util::traits::*;
 use dataframe::*;
 
-impl<'a, T> Constructor<'a, T> for DataFrame<T>
-    where T: UtahNum + 'a
+impl<'a, T, L> Constructor<'a, T, L> for DataFrame<T, L>
+    where T: UtahNum + 'a,
+          L: 'a + UtahLabel
 {
     /// Create a new dataframe. The only required argument is data to populate the dataframe.
-    /// By default, the columns and index of the dataframe are `["1", "2", "3"..."N"]`, where *N* is
-    /// the number of columns (or rows) in the data.
+    /// By default, the columns and index of the dataframe are the positions `0, 1, 2...N`, where
+    /// *N* is the number of columns (or rows) in the data, turned into labels of the label type
+    /// (`["0", "1", "2"..."N"]` for the default `String` labels).
     ///
     /// ```
     /// use utah::prelude::*;
@@ -27,7 +31,7 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
     ///                [InnerType::Int32(6), InnerType::Int64(10)]]);
     /// let df : DataFrame<InnerType> = DataFrame::new(a);
     /// ```
-    fn new<U: Clone>(data: Matrix<U>) -> DataFrame<T>
+    fn new<U: Clone>(data: Matrix<U>) -> DataFrame<T, L>
         where T: From<U>
     {
         let mut data: Matrix<T> = data.mapv(T::from);
@@ -39,9 +43,9 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
             }
         });
 
-        let columns: Vec<String> = (0..data.shape()[1]).map(|x| x.to_string()).collect();
+        let columns: Vec<L> = (0..data.shape()[1]).map(L::from_position).collect();
 
-        let index: Vec<String> = (0..data.shape()[0]).map(|x| x.to_string()).collect();
+        let index: Vec<L> = (0..data.shape()[0]).map(L::from_position).collect();
 
         DataFrame {
             data: data,
@@ -58,7 +62,7 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
     /// let df : DataFrame<f64> = DataFrame::from_array(a, UtahAxis::Column);
     /// ```
     ///
-    fn from_array<U: Clone>(data: Row<U>, axis: UtahAxis) -> DataFrame<T>
+    fn from_array<U: Clone>(data: Row<U>, axis: UtahAxis) -> DataFrame<T, L>
         where T: From<U>
     {
         let res_dim = match axis {
@@ -73,9 +77,9 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
                 return x;
             }
         });
-        let columns: Vec<String> = (0..res_dim.1).map(|x| x.to_string()).collect();
+        let columns: Vec<L> = (0..res_dim.1).map(L::from_position).collect();
 
-        let index: Vec<String> = (0..res_dim.0).map(|x| x.to_string()).collect();
+        let index: Vec<L> = (0..res_dim.0).map(L::from_position).collect();
 
         DataFrame {
             data: data,
@@ -83,6 +87,43 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
             index: index,
         }
     }
+
+    /// Generate a dataframe of the given shape with entries drawn independently from
+    /// `distribution`. The same seed always gives the same dataframe.
+    ///
+    /// ```
+    /// extern crate rand;
+    /// extern crate utah;
+    /// use utah::prelude::*;
+    /// use rand::distributions::Range;
+    ///
+    /// # fn main() {
+    /// let df : DataFrame<f64> = DataFrame::random((3, 2), Range::new(0., 1.), 42);
+    /// assert_eq!(df, DataFrame::random((3, 2), Range::new(0., 1.), 42));
+    /// # }
+    /// ```
+    fn random<D>(shape: (usize, usize), distribution: D, seed: u64) -> DataFrame<T, L>
+        where D: IndependentSample<T>
+    {
+        let data: Matrix<T> = Array::random_using(shape, distribution, &mut seeded_rng(seed));
+        DataFrame::new(data)
+    }
+
+    /// Generate a dataframe of the given shape whose entry at row `i` and column `j` is
+    /// `f(i, j)`.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let df : DataFrame<f64> = DataFrame::from_fn((2, 3), |i, j| (i * 3 + j) as f64);
+    /// assert_eq!(df.data[[1, 2]], 5.0);
+    /// ```
+    fn from_fn<F>(shape: (usize, usize), f: F) -> DataFrame<T, L>
+        where F: Fn(usize, usize) -> T
+    {
+        let c = (0..shape.0).flat_map(|i| (0..shape.1).map(move |j| (i, j))).map(|(i, j)| f(i, j));
+        let data: Matrix<T> = Array::from_shape_vec(shape, c.collect()).unwrap();
+        DataFrame::new(data)
+    }
     /// Populate the dataframe with a set of columns. The column elements can be any of `OuterType`. Example:
     ///
     /// ```
@@ -91,8 +132,8 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
     /// let df : Result<DataFrame<f64>> = DataFrame::new(a).columns(&["a", "b"]);
     /// df.is_ok();
     /// ```
-    fn columns<U: Clone>(mut self, columns: &'a [U]) -> Result<DataFrame<T>>
-        where String: From<U>
+    fn columns<U: Clone>(mut self, columns: &'a [U]) -> Result<DataFrame<T, L>>
+        where L: From<U>
     {
         let data_shape = self.data.shape()[1];
         let column_shape = columns.len();
@@ -101,7 +142,7 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
                                                       column_shape.to_string())
                 .into());
         }
-        let new_columns: Vec<String> = columns.iter()
+        let new_columns: Vec<L> = columns.iter()
             .map(|x| x.clone().into())
             .collect();
         self.columns = new_columns;
@@ -125,8 +166,8 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
     /// let df : Result<DataFrame<f64>> = DataFrame::new(a).index(&["1", "2"]).unwrap().columns(&["a", "b"]);
     /// df.is_ok();
     /// ```
-    fn index<U: Clone>(mut self, index: &'a [U]) -> Result<DataFrame<T>>
-        where String: From<U>
+    fn index<U: Clone>(mut self, index: &'a [U]) -> Result<DataFrame<T, L>>
+        where L: From<U>
     {
         let data_shape = self.data.shape()[0];
         let index_shape = index.len();
@@ -135,7 +176,7 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
                                                      index_shape.to_string())
                 .into());
         }
-        let new_index: Vec<String> = index.iter()
+        let new_index: Vec<L> = index.iter()
             .map(|x| x.clone().into())
             .collect();
         self.index = new_index;
@@ -154,7 +195,7 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
     /// let df : DataFrame<f64> = DataFrame::new(a).index(&["1", "2"]).unwrap().columns(&["a", "b"]).unwrap();
     /// let df_iter = df.df_iter(UtahAxis::Row);
     /// ```
-    fn df_iter(&'a self, axis: UtahAxis) -> DataFrameIterator<'a, T> {
+    fn df_iter(&'a self, axis: UtahAxis) -> DataFrameIterator<'a, T, L> {
         match axis {
             UtahAxis::Row => {
                 DataFrameIterator {
@@ -186,7 +227,7 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
     /// let mut df : DataFrame<f64> = DataFrame::new(a);
     /// let df_iter_mut = df.df_iter_mut(UtahAxis::Column);
     /// ```
-    fn df_iter_mut(&'a mut self, axis: UtahAxis) -> DataFrameMutIterator<'a, T> {
+    fn df_iter_mut(&'a mut self, axis: UtahAxis) -> DataFrameMutIterator<'a, T, L> {
         match axis {
             UtahAxis::Row => {
                 DataFrameMutIterator {
@@ -200,7 +241,7 @@ impl<'a, T> Constructor<'a, T> for DataFrame<T>
                 DataFrameMutIterator {
                     names: self.columns.iter(),
                     data: self.data.axis_iter_mut(Axis(1)),
-                    axis: UtahAxis::Row,
+                    axis: UtahAxis::Column,
                     other: self.index.clone(),
                 }
             }
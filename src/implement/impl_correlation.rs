@@ -0,0 +1,141 @@
+use util::types::*;
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use ndarray::{Array, ArrayView1, Axis};
+use num::traits::ToPrimitive;
+use std::cmp::Ordering;
+
+impl<T, L> Correlation<L> for DataFrame<T, L>
+    where T: UtahNum + ToPrimitive,
+          L: UtahLabel
+{
+    /// Compute the pairwise correlation of columns with the specified `CorrelationMethod`.
+    /// Empty values are excluded pair by pair, so each coefficient uses every row where both
+    /// columns are present.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [2.0, 4.0], [3.0, 7.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let res = df.corr(CorrelationMethod::Spearman).unwrap();
+    /// assert_eq!(res.data[[0, 1]], 1.0);
+    /// ```
+    fn corr(&self, method: CorrelationMethod) -> Result<DataFrame<f64, L>> {
+        let data = to_float_matrix(&self.data);
+        pairwise(&self.columns, &data, |x, y| match method {
+            CorrelationMethod::Pearson => pearson(&x, &y),
+            CorrelationMethod::Spearman => pearson(&rank(&x), &rank(&y)),
+            CorrelationMethod::Kendall => kendall(&x, &y),
+        })
+    }
+
+    /// Compute the pairwise sample covariance of columns. Empty values are excluded pair by
+    /// pair.
+    fn cov(&self) -> Result<DataFrame<f64, L>> {
+        let data = to_float_matrix(&self.data);
+        pairwise(&self.columns, &data, |x, y| covariance(&x, &y))
+    }
+}
+
+fn to_float_matrix<T>(data: &Matrix<T>) -> Matrix<f64>
+    where T: UtahNum + ToPrimitive
+{
+    data.map(|x| if x.is_empty() {
+        f64::NAN
+    } else {
+        x.to_f64().unwrap_or(f64::NAN)
+    })
+}
+
+/// Fill a symmetric matrix by applying `f` to every pair of columns, restricted to the rows where
+/// both columns hold a value.
+fn pairwise<L, F>(columns: &[L], data: &Matrix<f64>, f: F) -> Result<DataFrame<f64, L>>
+    where L: UtahLabel,
+          F: Fn(Vec<f64>, Vec<f64>) -> f64
+{
+    let ncols = data.shape()[1];
+    let mut res = Array::from_elem((ncols, ncols), f64::NAN);
+    for i in 0..ncols {
+        for j in i..ncols {
+            let (x, y) = complete_pairs(data.subview(Axis(1), i), data.subview(Axis(1), j));
+            let r = f(x, y);
+            res[[i, j]] = r;
+            res[[j, i]] = r;
+        }
+    }
+    DataFrame::new(res).columns(columns)?.index(columns)
+}
+
+fn complete_pairs(x: ArrayView1<f64>, y: ArrayView1<f64>) -> (Vec<f64>, Vec<f64>) {
+    x.iter()
+        .zip(y.iter())
+        .filter(|&(a, b)| !a.is_nan() && !b.is_nan())
+        .map(|(a, b)| (*a, *b))
+        .unzip()
+}
+
+fn mean(x: &[f64]) -> f64 {
+    x.iter().fold(0., |acc, v| acc + v) / x.len() as f64
+}
+
+fn covariance(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+    let (mx, my) = (mean(x), mean(y));
+    x.iter().zip(y.iter()).fold(0., |acc, (a, b)| acc + (a - mx) * (b - my)) / (n - 1) as f64
+}
+
+fn pearson(x: &[f64], y: &[f64]) -> f64 {
+    covariance(x, y) / (covariance(x, x) * covariance(y, y)).sqrt()
+}
+
+/// Rank values in ascending order, assigning tied values the average of their ranks.
+fn rank(x: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..x.len()).collect();
+    order.sort_by(|&a, &b| x[a].partial_cmp(&x[b]).unwrap_or(Ordering::Equal));
+    let mut ranks = vec![0.; x.len()];
+    let mut i = 0;
+    while i < order.len() {
+        let mut j = i + 1;
+        while j < order.len() && x[order[j]] == x[order[i]] {
+            j += 1;
+        }
+        let r = (i + j + 1) as f64 / 2.;
+        for k in i..j {
+            ranks[order[k]] = r;
+        }
+        i = j;
+    }
+    ranks
+}
+
+/// Kendall's tau-b, which accounts for ties in either variable.
+fn kendall(x: &[f64], y: &[f64]) -> f64 {
+    let n = x.len();
+    if n < 2 {
+        return f64::NAN;
+    }
+    let (mut concordant, mut discordant, mut ties_x, mut ties_y) = (0f64, 0f64, 0f64, 0f64);
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let dx = x[i] - x[j];
+            let dy = y[i] - y[j];
+            if dx == 0. && dy == 0. {
+                continue;
+            } else if dx == 0. {
+                ties_x += 1.;
+            } else if dy == 0. {
+                ties_y += 1.;
+            } else if dx * dy > 0. {
+                concordant += 1.;
+            } else {
+                discordant += 1.;
+            }
+        }
+    }
+    (concordant - discordant) /
+    ((concordant + discordant + ties_x) * (concordant + discordant + ties_y)).sqrt()
+}
@@ -0,0 +1,117 @@
+use util::types::*;
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use datetime::Datetime;
+use mixedtypes::{InnerType, OuterType};
+use implement::impl_duplicates::column_position;
+use combinators::resample::Resampler;
+use ndarray::Array;
+use std::ops::Range;
+
+impl DatetimeIndex for [Datetime] {
+    fn year(&self) -> Vec<i32> {
+        self.iter().map(|x| x.year()).collect()
+    }
+
+    fn month(&self) -> Vec<u32> {
+        self.iter().map(|x| x.month()).collect()
+    }
+
+    fn day(&self) -> Vec<u32> {
+        self.iter().map(|x| x.day()).collect()
+    }
+
+    fn weekday(&self) -> Vec<u32> {
+        self.iter().map(|x| x.weekday()).collect()
+    }
+}
+
+impl<T> TimeSeries<T> for DataFrame<T, OuterType>
+    where T: UtahNum
+{
+    /// The datetimes of the index, failing if any label is not a datetime.
+    fn datetime_index(&self) -> Result<Vec<Datetime>> {
+        self.index
+            .iter()
+            .map(|x| match *x {
+                OuterType::Datetime(d) => Ok(d),
+                ref x => Err(ErrorKind::NonDatetimeLabel(x.to_string()).into()),
+            })
+            .collect()
+    }
+
+    /// Select the rows whose datetime falls in `range`, which includes its start but not its
+    /// end. The bounds are parsed like `Datetime::from_str`.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0], [2.0], [3.0]]);
+    /// let dates: Vec<OuterType> = ["2016-01-01", "2016-01-15", "2016-02-01"]
+    ///     .iter()
+    ///     .map(|x| OuterType::Datetime(x.parse().unwrap()))
+    ///     .collect();
+    /// let df : DataFrame<f64, OuterType> = DataFrame::new(a).index(&dates[..]).unwrap();
+    /// let res = df.loc_range("2016-01-01".."2016-02-01").unwrap();
+    /// assert_eq!(res.data, arr2(&[[1.0], [2.0]]));
+    /// ```
+    fn loc_range(&self, range: Range<&str>) -> Result<DataFrame<T, OuterType>> {
+        let (start, end): (Datetime, Datetime) = (range.start.parse()?, range.end.parse()?);
+        let positions: Vec<usize> = self.datetime_index()?
+            .iter()
+            .enumerate()
+            .filter(|&(_, x)| start <= *x && *x < end)
+            .map(|(i, _)| i)
+            .collect();
+        self.take(&positions[..], UtahAxis::Row)
+    }
+
+    /// Regroup the rows on a regular grid of datetimes `freq` apart, such as `"1h"`, `"1D"` or
+    /// `"1W"`. Buckets are aligned to midnight, and weekly buckets start on Mondays.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0], [2.0], [4.0]]);
+    /// let dates: Vec<OuterType> = ["2016-01-01 09:00", "2016-01-01 17:00", "2016-01-02 12:00"]
+    ///     .iter()
+    ///     .map(|x| OuterType::Datetime(x.parse().unwrap()))
+    ///     .collect();
+    /// let df : DataFrame<f64, OuterType> = DataFrame::new(a).index(&dates[..]).unwrap();
+    /// let res = df.resample("1D").unwrap().agg(AggFunc::Sum).unwrap();
+    /// assert_eq!(res.data, arr2(&[[3.0], [4.0]]));
+    /// assert_eq!(res.index[1], OuterType::Datetime("2016-01-02".parse().unwrap()));
+    /// ```
+    fn resample<'a>(&'a self, freq: &str) -> Result<Resampler<'a, T>> {
+        Ok(Resampler::new(self, freq.parse()?))
+    }
+}
+
+impl ToDatetimeIndex for DataFrame<InnerType> {
+    /// Move a column of datetimes into the index. Strings in the column are parsed like
+    /// `Datetime::from_str`.
+    fn to_datetime_index(&self, column: &str) -> Result<DataFrame<InnerType, OuterType>> {
+        let j = column_position(self, column)?;
+        let mut index = Vec::with_capacity(self.index.len());
+        let mut c = Vec::with_capacity(self.index.len() * (self.columns.len() - 1));
+        for row in self.data.outer_iter() {
+            let date = match row[j] {
+                InnerType::Datetime(d) => d,
+                InnerType::Str(ref s) => s.parse()?,
+                ref x => {
+                    return Err(ErrorKind::ParseError(format!("invalid datetime '{:?}'", x))
+                        .into())
+                }
+            };
+            index.push(OuterType::Datetime(date));
+            c.extend(row.iter().enumerate().filter(|&(k, _)| k != j).map(|(_, x)| x.clone()));
+        }
+        let columns: Vec<String> = self.columns
+            .iter()
+            .enumerate()
+            .filter(|&(k, _)| k != j)
+            .map(|(_, x)| x.clone())
+            .collect();
+        let d = Array::from_shape_vec((index.len(), columns.len()), c).unwrap();
+        DataFrame::new(d).columns(&columns[..])?.index(&index[..])
+    }
+}
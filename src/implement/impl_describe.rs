@@ -0,0 +1,150 @@
+use util::types::*;
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use mixedtypes::*;
+use ndarray::{Array, Axis};
+
+const NUMERIC_STATS: [&str; 8] = ["count", "mean", "std", "min", "25%", "50%", "75%", "max"];
+const OBJECT_STATS: [&str; 4] = ["count", "unique", "top", "freq"];
+
+impl<L> Describe<f64, L> for DataFrame<f64, L>
+    where L: UtahLabel + From<&'static str>
+{
+    /// Generate summary statistics for each column of the dataframe. Empty values are excluded.
+    /// The summary keeps the labels of the columns, and is indexed by the names of the
+    /// statistics, so the labels have to be able to hold strings.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[2.0, 7.0], [3.0, std::f64::NAN], [4.0, 1.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let summary = df.describe().unwrap();
+    /// assert_eq!(summary.index, vec!["count", "mean", "std", "min", "25%", "50%", "75%", "max"]);
+    /// ```
+    fn describe(&self) -> Result<DataFrame<f64, L>> {
+        let count = self.countdf(UtahAxis::Column).as_array()?;
+        let mean = self.df_iter(UtahAxis::Column)
+            .zip(count.iter())
+            .map(|((_, col), n)| {
+                col.iter().filter(|x| !x.is_empty()).fold(0., |acc, x| acc + x) / n
+            })
+            .collect::<Vec<f64>>();
+        let std = self.var(UtahAxis::Column).as_array()?.mapv(f64::sqrt);
+        let mut stats = vec![count, Array::from_vec(mean), std];
+        for &(q, interpolation) in [(0., Interpolation::Lower),
+                                    (0.25, Interpolation::Linear),
+                                    (0.5, Interpolation::Linear),
+                                    (0.75, Interpolation::Linear),
+                                    (1., Interpolation::Higher)]
+            .iter() {
+            stats.push(self.quantile(q, interpolation, UtahAxis::Column)?.as_array()?);
+        }
+
+        let ncols = self.columns.len();
+        let mut data = Array::from_elem((NUMERIC_STATS.len(), ncols), f64::empty());
+        for (mut row, stat) in data.axis_iter_mut(Axis(0)).zip(stats.iter()) {
+            row.assign(stat);
+        }
+        DataFrame::new(data).columns(&self.columns[..])?.index(&NUMERIC_STATS[..])
+    }
+}
+
+impl<L> Describe<InnerType, L> for DataFrame<InnerType, L>
+    where L: UtahLabel + From<&'static str>
+{
+    /// Generate summary statistics for each column of the dataframe. Empty values are excluded.
+    ///
+    /// Numeric columns get the same statistics as a `DataFrame<f64>`, while columns holding
+    /// strings get `count`, `unique`, `top` (the most frequent value) and `freq` (its
+    /// frequency). Statistics that don't apply to a column are left `Empty`.
+    fn describe(&self) -> Result<DataFrame<InnerType, L>> {
+        let names = column_names(self);
+        let is_numeric: Vec<bool> = self.df_iter(UtahAxis::Column)
+            .map(|(_, col)| col.iter().all(|x| x.is_empty() || to_f64(x).is_some()))
+            .collect();
+        let numeric: Vec<usize> = (0..names.len()).filter(|&j| is_numeric[j]).collect();
+        let object: Vec<usize> = (0..names.len()).filter(|&j| !is_numeric[j]).collect();
+        let names_of = |positions: &[usize]| -> Vec<String> {
+            positions.iter().map(|&j| names[j].clone()).collect()
+        };
+
+        let mut index: Vec<&'static str> = Vec::new();
+        if !object.is_empty() {
+            index.extend(OBJECT_STATS.iter());
+        }
+        if !numeric.is_empty() {
+            index.extend(NUMERIC_STATS.iter().filter(|x| !index.contains(x)).collect::<Vec<_>>());
+        }
+        let mut data = Array::from_elem((index.len(), names.len()), InnerType::Empty);
+
+        if !numeric.is_empty() {
+            let values = self.take(&numeric[..], UtahAxis::Column)?
+                .data
+                .mapv(|x| to_f64(&x).unwrap_or(f64::empty()));
+            let values: DataFrame<f64> =
+                DataFrame::new(values).columns(&names_of(&numeric)[..])?;
+            let summary = values.describe()?;
+            for (j, &k) in numeric.iter().enumerate() {
+                for (i, stat) in summary.index.iter().enumerate() {
+                    let r = index.iter().position(|x| x == stat).unwrap();
+                    data[[r, k]] = InnerType::Float(summary.data[[i, j]]);
+                }
+            }
+        }
+
+        if !object.is_empty() {
+            let values = self.take(&object[..], UtahAxis::Column)?
+                .data
+                .mapv(|x| if x.is_empty() { x } else { InnerType::Str(to_label(&x)) });
+            let values: DataFrame<InnerType> =
+                DataFrame::new(values).columns(&names_of(&object)[..])?;
+            let count = values.countdf(UtahAxis::Column).as_array()?;
+            let unique = values.nunique(UtahAxis::Column).as_array()?;
+            let top = values.mode(UtahAxis::Column).as_array()?;
+            let columns = object.iter().zip(values.df_iter(UtahAxis::Column));
+            for (j, (&k, (_, dat))) in columns.enumerate() {
+                let freq = dat.iter().filter(|&x| *x == top[j]).count();
+                data[[0, k]] = count[j].clone();
+                data[[1, k]] = unique[j].clone();
+                data[[2, k]] = top[j].clone();
+                data[[3, k]] = InnerType::Float(freq as f64);
+            }
+        }
+
+        DataFrame::new(data).columns(&self.columns[..])?.index(&index[..])
+    }
+}
+
+/// The labels of the columns of `df`, as displayed.
+fn column_names<T, L>(df: &DataFrame<T, L>) -> Vec<String>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    df.columns.iter().map(|x| x.to_string()).collect()
+}
+
+fn to_f64(x: &InnerType) -> Option<f64> {
+    match *x {
+        InnerType::Float(f) => Some(f),
+        InnerType::Int64(i) => Some(i as f64),
+        InnerType::Int32(i) => Some(i as f64),
+        InnerType::Float32(f) => Some(f as f64),
+        InnerType::UInt64(i) => Some(i as f64),
+        _ => None,
+    }
+}
+
+fn to_label(x: &InnerType) -> String {
+    match *x {
+        InnerType::Float(f) => f.to_string(),
+        InnerType::Int64(i) => i.to_string(),
+        InnerType::Int32(i) => i.to_string(),
+        InnerType::Float32(f) => f.to_string(),
+        InnerType::UInt64(i) => i.to_string(),
+        InnerType::Bool(b) => b.to_string(),
+        InnerType::Str(ref s) => s.clone(),
+        InnerType::Datetime(d) => d.to_string(),
+        InnerType::Empty => String::new(),
+    }
+}
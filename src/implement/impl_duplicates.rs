@@ -0,0 +1,96 @@
+use util::types::*;
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use ndarray::Array;
+use std::collections::{HashMap, HashSet};
+
+impl<T, L> Duplicates<T, L> for DataFrame<T, L>
+    where T: UtahNum + ToHashKey,
+          L: UtahLabel
+{
+    /// Flag every row that duplicates another, comparing all columns or only those in
+    /// `subset`. With `Keep::First` or `Keep::Last`, the first or last occurrence of each row
+    /// is not flagged; with `Keep::None`, every occurrence is.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [1.0, 3.0], [1.0, 2.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let res = df.duplicated(None, Keep::First).unwrap();
+    /// assert_eq!(res[2], ("2".to_string(), true));
+    /// ```
+    fn duplicated(&self, subset: Option<&[&str]>, keep: Keep) -> Result<Vec<(L, bool)>> {
+        let keys = row_keys(self, subset)?;
+        let mut counts: HashMap<&Vec<HashKey>, usize> = HashMap::new();
+        for key in &keys {
+            *counts.entry(key).or_insert(0) += 1;
+        }
+
+        let mut seen: HashMap<&Vec<HashKey>, usize> = HashMap::new();
+        let mut mask = Vec::with_capacity(keys.len());
+        for (label, key) in self.index.iter().zip(keys.iter()) {
+            let occurrence = seen.entry(key).or_insert(0);
+            *occurrence += 1;
+            let duplicate = match keep {
+                Keep::First => *occurrence > 1,
+                Keep::Last => *occurrence < counts[key],
+                Keep::None => counts[key] > 1,
+            };
+            mask.push((label.clone(), duplicate));
+        }
+        Ok(mask)
+    }
+
+    /// Remove the rows flagged by `duplicated`, keeping the remaining rows in order.
+    fn drop_duplicates(&self, subset: Option<&[&str]>, keep: Keep) -> Result<DataFrame<T, L>> {
+        let positions: Vec<usize> = self.duplicated(subset, keep)?
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(_, duplicate))| !duplicate)
+            .map(|(i, _)| i)
+            .collect();
+        self.take(&positions[..], UtahAxis::Row)
+    }
+
+    /// Get the distinct values of a column in order of first appearance. Empty values are
+    /// collapsed into a single entry.
+    fn unique(&self, column: &str) -> Result<Row<T>> {
+        let j = column_position(self, column)?;
+        let mut seen = HashSet::new();
+        let values: Vec<T> = self.data
+            .column(j)
+            .iter()
+            .filter(|x| seen.insert(x.hash_key()))
+            .cloned()
+            .collect();
+        Ok(Array::from_vec(values))
+    }
+}
+
+/// The position of the column called `name`. Columns are named by their label as displayed, so
+/// that a column labeled `OuterType::Int64(3)` or `3` is called `"3"`.
+pub(crate) fn column_position<T, L>(df: &DataFrame<T, L>, name: &str) -> Result<usize>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    match df.columns.iter().position(|x| x.to_string() == name) {
+        Some(j) => Ok(j),
+        None => Err(ErrorKind::InvalidColumnName(name.to_string()).into()),
+    }
+}
+
+/// The hash keys of every row, restricted to the columns in `subset` if given.
+fn row_keys<T, L>(df: &DataFrame<T, L>, subset: Option<&[&str]>) -> Result<Vec<Vec<HashKey>>>
+    where T: UtahNum + ToHashKey,
+          L: UtahLabel
+{
+    let positions: Vec<usize> = match subset {
+        Some(names) => names.iter().map(|x| column_position(df, x)).collect::<Result<_>>()?,
+        None => (0..df.columns.len()).collect(),
+    };
+    Ok(df.data
+        .outer_iter()
+        .map(|row| positions.iter().map(|&j| row[j].hash_key()).collect())
+        .collect())
+}
@@ -1,5 +1,7 @@
-use util::traits::Empty;
+use util::traits::{Empty, ToHashKey};
+use util::types::HashKey;
 use std::f64::NAN;
+use std::f32;
 
 impl Empty<f64> for f64 {
     fn empty() -> f64 {
@@ -19,6 +21,45 @@ impl Empty<i32> for i32 {
     }
 }
 
+// 64-bit integers have no value to spare for a missing entry, so none of them counts as empty;
+// `0` only fills cells that have no entry. Use `InnerType` to tell missing entries apart.
+impl Empty<i64> for i64 {
+    fn empty() -> i64 {
+        0
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl Empty<u64> for u64 {
+    fn empty() -> u64 {
+        0
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+impl Empty<f32> for f32 {
+    fn empty() -> f32 {
+        f32::NAN
+    }
+    fn is_empty(&self) -> bool {
+        self.is_nan()
+    }
+}
+
+// `false` is a value like any other, so no boolean is empty.
+impl Empty<bool> for bool {
+    fn empty() -> bool {
+        false
+    }
+    fn is_empty(&self) -> bool {
+        false
+    }
+}
+
 impl Empty<Option<i32>> for Option<i32> {
     fn empty() -> Option<i32> {
         None
@@ -37,3 +78,55 @@ impl Empty<Option<f64>> for Option<f64> {
         self.is_none()
     }
 }
+
+impl ToHashKey for f64 {
+    fn hash_key(&self) -> HashKey {
+        if self.is_empty() {
+            HashKey::Empty
+        } else if *self == 0. {
+            // Negative zero equals zero, so it has to hash the same.
+            HashKey::Float(0f64.to_bits())
+        } else {
+            HashKey::Float(self.to_bits())
+        }
+    }
+}
+
+impl ToHashKey for i32 {
+    fn hash_key(&self) -> HashKey {
+        if self.is_empty() {
+            HashKey::Empty
+        } else {
+            HashKey::Int(*self as i64)
+        }
+    }
+}
+
+impl ToHashKey for i64 {
+    fn hash_key(&self) -> HashKey {
+        HashKey::Int(*self)
+    }
+}
+
+impl ToHashKey for u64 {
+    fn hash_key(&self) -> HashKey {
+        if *self <= i64::MAX as u64 {
+            // Unsigned entries that fit an i64 hash like the signed integers they equal.
+            HashKey::Int(*self as i64)
+        } else {
+            HashKey::UInt(*self)
+        }
+    }
+}
+
+impl ToHashKey for f32 {
+    fn hash_key(&self) -> HashKey {
+        (*self as f64).hash_key()
+    }
+}
+
+impl ToHashKey for bool {
+    fn hash_key(&self) -> HashKey {
+        HashKey::Bool(*self)
+    }
+}
@@ -0,0 +1,114 @@
+use util::types::*;
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use ndarray::Array;
+use std::collections::HashMap;
+use mixedtypes::OuterType;
+use implement::impl_duplicates::column_position;
+
+impl<T, L> Frequency<T> for DataFrame<T, L>
+    where T: UtahNum + ToHashKey,
+          L: UtahLabel
+{
+    /// Count the occurrences of every distinct value of a column, in a single-column dataframe
+    /// labeled by value, as `HashKey::to_label` gives it. Empty values are not counted. With `normalize`, the counts are divided
+    /// by their total; with `sort`, the most frequent values come first, and otherwise values
+    /// appear in order of first occurrence.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0], [2.0], [2.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a"]).unwrap();
+    /// let res = df.value_counts("a", false, true).unwrap();
+    /// assert_eq!(res.index, vec![OuterType::from(2.0), OuterType::from(1.0)]);
+    /// ```
+    fn value_counts(&self,
+                    column: &str,
+                    normalize: bool,
+                    sort: bool)
+                    -> Result<DataFrame<f64, OuterType>> {
+        let j = column_position(self, column)?;
+        let (keys, counts) = tally(self.data.column(j).iter().map(|x| x.hash_key()));
+        let mut order: Vec<usize> = (0..keys.len()).collect();
+        if sort {
+            // The sort is stable, so ties stay in order of first occurrence.
+            order.sort_by(|&x, &y| counts[y].cmp(&counts[x]));
+        }
+        let total: usize = counts.iter().sum();
+        let values: Vec<f64> = order.iter()
+            .map(|&i| if normalize {
+                counts[i] as f64 / total as f64
+            } else {
+                counts[i] as f64
+            })
+            .collect();
+        let index: Vec<OuterType> = order.iter().filter_map(|&i| keys[i].to_label()).collect();
+
+        let name = if normalize { "proportion" } else { "count" };
+        let d = Array::from_shape_vec((values.len(), 1), values).unwrap();
+        DataFrame::new(d).columns(&[name])?.index(&index[..])
+    }
+
+    /// Count the co-occurrences of the values of two columns, in a contingency table with the
+    /// values of `a` as the index and the values of `b` as the columns. Rows where either
+    /// value is empty are not counted.
+    fn crosstab(&self, a: &str, b: &str) -> Result<DataFrame<f64, OuterType>> {
+        let (i, j) = (column_position(self, a)?, column_position(self, b)?);
+        let pairs: Vec<(HashKey, HashKey)> = self.data
+            .outer_iter()
+            .map(|row| (row[i].hash_key(), row[j].hash_key()))
+            .filter(|(x, y)| *x != HashKey::Empty && *y != HashKey::Empty)
+            .collect();
+        let (index, _) = tally(pairs.iter().map(|x| x.0.clone()));
+        let (columns, _) = tally(pairs.iter().map(|x| x.1.clone()));
+        let rows: HashMap<&HashKey, usize> =
+            index.iter().enumerate().map(|(i, x)| (x, i)).collect();
+        let cols: HashMap<&HashKey, usize> =
+            columns.iter().enumerate().map(|(i, x)| (x, i)).collect();
+
+        let mut d = Array::from_elem((index.len(), columns.len()), 0.);
+        for (x, y) in &pairs {
+            d[[rows[x], cols[y]]] += 1.;
+        }
+        let index: Vec<OuterType> = index.iter().filter_map(HashKey::to_label).collect();
+        let columns: Vec<OuterType> = columns.iter().filter_map(HashKey::to_label).collect();
+        DataFrame::new(d).columns(&columns[..])?.index(&index[..])
+    }
+}
+
+/// Build a contingency table of two columns of `df`. See `Frequency::crosstab`.
+///
+/// ```
+/// use utah::prelude::*;
+/// let a = arr2(&[[1.0, 2.0], [1.0, 3.0], [1.0, 2.0]]);
+/// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+/// let res = crosstab(&df, "a", "b").unwrap();
+/// assert_eq!(res.data, arr2(&[[2.0, 1.0]]));
+/// ```
+pub fn crosstab<T>(df: &DataFrame<T>, a: &str, b: &str) -> Result<DataFrame<f64, OuterType>>
+    where T: UtahNum + ToHashKey
+{
+    df.crosstab(a, b)
+}
+
+/// The distinct non-empty keys in order of first occurrence, with how often each occurs.
+fn tally<I>(keys: I) -> (Vec<HashKey>, Vec<usize>)
+    where I: Iterator<Item = HashKey>
+{
+    let mut positions: HashMap<HashKey, usize> = HashMap::new();
+    let (mut distinct, mut counts) = (Vec::new(), Vec::new());
+    for key in keys {
+        if key == HashKey::Empty {
+            continue;
+        }
+        let next = distinct.len();
+        let i = *positions.entry(key.clone()).or_insert(next);
+        if i == next {
+            distinct.push(key);
+            counts.push(0);
+        }
+        counts[i] += 1;
+    }
+    (distinct, counts)
+}
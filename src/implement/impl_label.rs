@@ -0,0 +1,26 @@
+use util::traits::UtahLabel;
+use mixedtypes::OuterType;
+
+impl UtahLabel for String {
+    fn from_position(position: usize) -> String {
+        position.to_string()
+    }
+}
+
+impl UtahLabel for OuterType {
+    fn from_position(position: usize) -> OuterType {
+        OuterType::USize(position)
+    }
+}
+
+macro_rules! impl_integer_label {
+    ($($t:ty),*) => {
+        $(impl UtahLabel for $t {
+            fn from_position(position: usize) -> $t {
+                position as $t
+            }
+        })*
+    }
+}
+
+impl_integer_label!(usize, u32, u64, i32, i64);
@@ -0,0 +1,146 @@
+use util::error::*;
+use util::traits::*;
+use util::types::*;
+use dataframe::*;
+use ndarray::{Array, ArrayView1};
+use std::cmp::Ordering;
+
+impl<T, L> Modify<T, L> for DataFrame<T, L>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    /// Insert a column before the column at `position`, or after the last one if `position` is
+    /// the number of columns. The column must have one entry per row and a new name.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1., 2.], [3., 4.]]);
+    /// let mut df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "c"]).unwrap();
+    /// df.insert_column("b", 1, arr1(&[5., 6.]).view()).unwrap();
+    /// assert_eq!(df.columns, vec!["a", "b", "c"]);
+    /// assert_eq!(df.data, arr2(&[[1., 5., 2.], [3., 6., 4.]]));
+    /// assert!(df.insert_column("d", 0, arr1(&[1.]).view()).is_err());
+    /// ```
+    fn insert_column<U>(&mut self, name: U, position: usize, data: ArrayView1<T>) -> Result<()>
+        where L: From<U>
+    {
+        let name: L = name.into();
+        if data.len() != self.index.len() {
+            return Err(ErrorKind::IndexShapeMismatch(self.index.len().to_string(),
+                                                     data.len().to_string())
+                .into());
+        }
+        if position > self.columns.len() {
+            return Err(ErrorKind::PositionOutOfBounds(position, self.columns.len()).into());
+        }
+        if self.columns.contains(&name) {
+            return Err(ErrorKind::DuplicateColumnName(name.to_string()).into());
+        }
+        let shape = (self.index.len(), self.columns.len() + 1);
+        self.data = Array::from_shape_fn(shape, |(i, j)| match j.cmp(&position) {
+            Ordering::Less => self.data[[i, j]].clone(),
+            Ordering::Equal => data[i].clone(),
+            Ordering::Greater => self.data[[i, j - 1]].clone(),
+        });
+        self.columns.insert(position, name);
+        Ok(())
+    }
+
+    /// Replace the entries of the column `name`, or add it as the last column if there is none.
+    fn set_column<U>(&mut self, name: U, data: ArrayView1<T>) -> Result<()>
+        where L: From<U>
+    {
+        let name: L = name.into();
+        match self.columns.iter().position(|x| *x == name) {
+            Some(j) => {
+                if data.len() != self.index.len() {
+                    return Err(ErrorKind::IndexShapeMismatch(self.index.len().to_string(),
+                                                             data.len().to_string())
+                        .into());
+                }
+                self.data.column_mut(j).assign(&data);
+                Ok(())
+            }
+            None => {
+                let position = self.columns.len();
+                self.insert_column::<L>(name, position, data)
+            }
+        }
+    }
+
+    /// Relabel rows or columns, from the first label of each pair of `map` to the second. Columns
+    /// can't be renamed to a label that another column ends up with.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1., 2.]]);
+    /// let mut df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// df.rename(&[("a", "x")], UtahAxis::Column).unwrap();
+    /// assert_eq!(df.columns, vec!["x", "b"]);
+    /// assert!(df.rename(&[("a", "y")], UtahAxis::Column).is_err());
+    /// assert!(df.rename(&[("x", "b")], UtahAxis::Column).is_err());
+    /// ```
+    fn rename<U: Clone>(&mut self, map: &[(U, U)], axis: UtahAxis) -> Result<()>
+        where L: From<U>
+    {
+        let labels = match axis {
+            UtahAxis::Row => &mut self.index,
+            UtahAxis::Column => &mut self.columns,
+        };
+        let map: Vec<(L, L)> = map.iter()
+            .map(|(from, to)| (from.clone().into(), to.clone().into()))
+            .collect();
+        if let Some((from, _)) = map.iter().find(|(from, _)| !labels.contains(from)) {
+            return Err(missing_label(from, axis));
+        }
+        let renamed: Vec<L> = labels.iter()
+            .map(|label| match map.iter().find(|(from, _)| from == label) {
+                Some((_, to)) => to.clone(),
+                None => label.clone(),
+            })
+            .collect();
+        if let UtahAxis::Column = axis {
+            if let Some((_, to)) = map.iter()
+                .find(|(_, to)| renamed.iter().filter(|x| *x == to).count() > 1) {
+                return Err(ErrorKind::DuplicateColumnName(to.to_string()).into());
+            }
+        }
+        *labels = renamed;
+        Ok(())
+    }
+
+    /// Remove the rows or columns labelled `names`.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1., 2.], [3., 4.]]);
+    /// let mut df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// df.drop(&["0"], UtahAxis::Row).unwrap();
+    /// df.drop(&["b"], UtahAxis::Column).unwrap();
+    /// assert_eq!(df.data, arr2(&[[3.]]));
+    /// ```
+    fn drop<U: Clone>(&mut self, names: &[U], axis: UtahAxis) -> Result<()>
+        where L: From<U>
+    {
+        let names: Vec<L> = names.iter().map(|x| x.clone().into()).collect();
+        let labels = match axis {
+            UtahAxis::Row => &self.index,
+            UtahAxis::Column => &self.columns,
+        };
+        if let Some(name) = names.iter().find(|x| !labels.contains(x)) {
+            return Err(missing_label(name, axis));
+        }
+        let positions: Vec<usize> =
+            (0..labels.len()).filter(|&i| !names.contains(&labels[i])).collect();
+        *self = self.take(&positions[..], axis)?;
+        Ok(())
+    }
+}
+
+/// The error for a label that isn't on `axis`.
+fn missing_label<L: UtahLabel>(label: &L, axis: UtahAxis) -> Error {
+    match axis {
+        UtahAxis::Row => ErrorKind::InvalidRowName(label.to_string()).into(),
+        UtahAxis::Column => ErrorKind::InvalidColumnName(label.to_string()).into(),
+    }
+}
@@ -0,0 +1,91 @@
+use util::types::*;
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use multiindex::*;
+use mixedtypes::OuterType;
+use ndarray::Array;
+use std::collections::HashMap;
+use implement::impl_duplicates::column_position;
+use implement::impl_reshape::aggregate;
+
+impl<T, L> Hierarchical<T> for DataFrame<T, L>
+    where T: UtahNum + ToHashKey,
+          L: UtahLabel
+{
+    /// Move the `keys` columns into a hierarchical index, one level per column, outermost
+    /// first. Empty keys are labeled with an empty string.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[2016.0, 1.0, 5.0], [2016.0, 2.0, 6.0], [2017.0, 1.0, 7.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["year", "site", "temp"]).unwrap();
+    /// let res = df.set_index(&["year", "site"]).unwrap();
+    /// let res = res.loc(&[OuterType::from(2016.0)]).unwrap();
+    /// assert_eq!(res.data, arr2(&[[5.0], [6.0]]));
+    /// ```
+    fn set_index(&self, keys: &[&str]) -> Result<MultiIndexFrame<T>> {
+        let levels: Vec<usize> = keys.iter()
+            .map(|x| column_position(self, x))
+            .collect::<Result<_>>()?;
+        let rest: Vec<usize> = (0..self.columns.len()).filter(|j| !levels.contains(j)).collect();
+
+        let mut c = Vec::with_capacity(self.index.len() * rest.len());
+        let mut index = Vec::with_capacity(self.index.len());
+        for row in self.data.outer_iter() {
+            index.push(levels.iter().map(|&j| label(&row[j].hash_key())).collect());
+            c.extend(rest.iter().map(|&j| row[j].clone()));
+        }
+        let columns: Vec<String> = rest.iter().map(|&j| self.columns[j].to_string()).collect();
+        let d = Array::from_shape_vec((self.index.len(), rest.len()), c).unwrap();
+        MultiIndexFrame::new(d,
+                             MultiIndex::new(keys, index)?,
+                             MultiIndex::from_labels("columns", &columns[..]))
+    }
+
+    /// Group the rows by the distinct combinations of the `keys` columns, and reduce every
+    /// other column within each group with the aggregate combinator given by `aggfunc`. The
+    /// result is indexed by the group keys, in order of first occurrence. Rows with an empty
+    /// key are left out. Unstacking the result gives a pivot table over several levels.
+    fn groupby(&self, keys: &[&str], aggfunc: AggFunc) -> Result<MultiIndexFrame<T>>
+        where T: PartialOrd
+    {
+        let levels: Vec<usize> = keys.iter()
+            .map(|x| column_position(self, x))
+            .collect::<Result<_>>()?;
+        let rest: Vec<usize> = (0..self.columns.len()).filter(|j| !levels.contains(j)).collect();
+
+        let mut positions: HashMap<Vec<HashKey>, usize> = HashMap::new();
+        let mut index: Vec<Vec<OuterType>> = Vec::new();
+        let mut groups: Vec<Vec<Vec<T>>> = Vec::new();
+        for row in self.data.outer_iter() {
+            let key: Vec<HashKey> = levels.iter().map(|&j| row[j].hash_key()).collect();
+            if key.contains(&HashKey::Empty) {
+                continue;
+            }
+            let next = index.len();
+            let g = *positions.entry(key.clone()).or_insert(next);
+            if g == next {
+                index.push(key.iter().map(label).collect());
+                groups.push(vec![Vec::new(); rest.len()]);
+            }
+            for (k, &j) in rest.iter().enumerate() {
+                groups[g][k].push(row[j].clone());
+            }
+        }
+
+        let mut c = Vec::with_capacity(groups.len() * rest.len());
+        for group in groups {
+            c.extend(group.into_iter().map(|entries| aggregate(entries, aggfunc)));
+        }
+        let columns: Vec<String> = rest.iter().map(|&j| self.columns[j].to_string()).collect();
+        let d = Array::from_shape_vec((index.len(), rest.len()), c).unwrap();
+        MultiIndexFrame::new(d,
+                             MultiIndex::new(keys, index)?,
+                             MultiIndex::from_labels("columns", &columns[..]))
+    }
+}
+
+pub(crate) fn label(key: &HashKey) -> OuterType {
+    key.to_label().unwrap_or(OuterType::Str(String::new()))
+}
@@ -4,27 +4,138 @@ use combinators::aggregate::*;
 use combinators::process::*;
 use combinators::interact::*;
 use combinators::transform::*;
+use combinators::lag::*;
+use combinators::rolling::*;
 use util::traits::*;
 use dataframe::*;
-use ndarray::ArrayView1;
+use ndarray::{Array, ArrayView1, Axis};
+use util::error::*;
+use std::cmp::min;
+use std::ops::Range;
 
 #[cfg(not(feature = "specialization"))]
-impl<'a, T> Operations<'a, T> for DataFrame<T>
-    where T: 'a + UtahNum
+impl<'a, T, L> Operations<'a, T, L> for DataFrame<T, L>
+    where T: 'a + UtahNum,
+          L: 'a + UtahLabel
 {
     /// Get the dimensions of the dataframe.
     fn shape(self) -> (usize, usize) {
         self.data.dim()
     }
 
+    /// Swap the index and columns of the dataframe, transposing its data.
+    ///
+    /// This does not copy the data: the underlying matrix just has its strides reversed.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let res = df.transpose();
+    /// assert_eq!(res.shape(), (2, 3));
+    /// ```
+    fn transpose(self) -> DataFrame<T, L> {
+        DataFrame {
+            columns: self.index,
+            data: self.data.reversed_axes(),
+            index: self.columns,
+        }
+    }
+
+    /// Get a transposed copy of the dataframe, leaving the original in place.
+    fn t(&'a self) -> DataFrame<T, L> {
+        self.clone().transpose()
+    }
+
+    /// Get the first `n` rows of the dataframe, or all of them if there are fewer.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let res = df.head(2);
+    /// assert_eq!(res.index, vec!["0", "1"]);
+    /// ```
+    fn head(&'a self, n: usize) -> DataFrame<T, L> {
+        self.slice(0..n, UtahAxis::Row)
+    }
+
+    /// Get the last `n` rows of the dataframe, or all of them if there are fewer.
+    fn tail(&'a self, n: usize) -> DataFrame<T, L> {
+        let len = self.index.len();
+        self.slice(len.saturating_sub(n)..len, UtahAxis::Row)
+    }
+
+    /// Get the rows or columns at positions `range` along the specified `UtahAxis`. The range
+    /// is clipped to the length of the axis.
+    fn slice(&'a self, range: Range<usize>, axis: UtahAxis) -> DataFrame<T, L> {
+        match axis {
+            UtahAxis::Row => {
+                let end = min(range.end, self.index.len());
+                let start = min(range.start, end);
+                DataFrame {
+                    columns: self.columns.clone(),
+                    data: self.data.slice(s![start as isize..end as isize, ..]).to_owned(),
+                    index: self.index[start..end].to_vec(),
+                }
+            }
+            UtahAxis::Column => {
+                let end = min(range.end, self.columns.len());
+                let start = min(range.start, end);
+                DataFrame {
+                    columns: self.columns[start..end].to_vec(),
+                    data: self.data.slice(s![.., start as isize..end as isize]).to_owned(),
+                    index: self.index.clone(),
+                }
+            }
+        }
+    }
+
+    /// Get the rows or columns at the given positions along the specified `UtahAxis`, in the
+    /// order given. Positions may repeat.
+    fn take(&'a self, positions: &[usize], axis: UtahAxis) -> Result<DataFrame<T, L>> {
+        let (labels, ax) = match axis {
+            UtahAxis::Row => (&self.index, Axis(0)),
+            UtahAxis::Column => (&self.columns, Axis(1)),
+        };
+        let mut c = Vec::new();
+        let mut taken = Vec::new();
+        for &p in positions {
+            if p >= labels.len() {
+                return Err(ErrorKind::PositionOutOfBounds(p, labels.len()).into());
+            }
+            c.extend(self.data.subview(ax, p).iter().cloned());
+            taken.push(labels[p].clone());
+        }
+
+        match axis {
+            UtahAxis::Row => {
+                let d = Array::from_shape_vec((taken.len(), self.columns.len()), c).unwrap();
+                Ok(DataFrame {
+                    columns: self.columns.clone(),
+                    data: d,
+                    index: taken,
+                })
+            }
+            UtahAxis::Column => {
+                let d = Array::from_shape_vec((taken.len(), self.index.len()), c).unwrap();
+                Ok(DataFrame {
+                    columns: taken,
+                    data: d.reversed_axes(),
+                    index: self.index.clone(),
+                })
+            }
+        }
+    }
+
 
 
     /// Select rows or columns over the specified `UtahAxis`.
-    fn select<U: ?Sized>(&'a self, names: &'a [&'a U], axis: UtahAxis) -> SelectIter<'a, T>
-        where String: From<&'a U>
+    fn select<U: ?Sized + ToOwned>(&'a self, names: &'a [&'a U], axis: UtahAxis) -> SelectIter<'a, T, L>
+        where L: From<U::Owned>
     {
-        let names: Vec<String> = names.iter()
-            .map(|x| (*x).into())
+        let names: Vec<L> = names.iter()
+            .map(|x| (*x).to_owned().into())
             .collect();
         match axis {
             UtahAxis::Row => {
@@ -43,11 +154,11 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
     }
 
     /// Remove rows or columns over the specified `UtahAxis`.
-    fn remove<U: ?Sized>(&'a self, names: &'a [&'a U], axis: UtahAxis) -> RemoveIter<'a, T>
-        where String: From<&'a U>
+    fn remove<U: ?Sized + ToOwned>(&'a self, names: &'a [&'a U], axis: UtahAxis) -> RemoveIter<'a, T, L>
+        where L: From<U::Owned>
     {
-        let names: Vec<String> = names.iter()
-            .map(|x| (*x).into())
+        let names: Vec<L> = names.iter()
+            .map(|x| (*x).to_owned().into())
             .collect();
         match axis {
             UtahAxis::Row => {
@@ -66,14 +177,14 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
     }
 
     /// Append  a row or column along the specified `UtahAxis`.
-    fn append<U: ?Sized>(&'a mut self,
+    fn append<U: ?Sized + ToOwned>(&'a mut self,
                          name: &'a U,
                          data: ArrayView1<'a, T>,
                          axis: UtahAxis)
-                         -> AppendIter<'a, T>
-        where String: From<&'a U>
+                         -> AppendIter<'a, T, L>
+        where L: From<U::Owned>
     {
-        let name: String = name.into();
+        let name: L = name.to_owned().into();
         match axis {
             UtahAxis::Row => {
                 Append::new(self.df_iter(UtahAxis::Row),
@@ -95,7 +206,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
 
 
     /// Perform an inner left join between two dataframes along the specified `UtahAxis`.
-    fn inner_left_join(&'a self, other: &'a DataFrame<T>) -> InnerJoinIter<'a, T> {
+    fn inner_left_join(&'a self, other: &'a DataFrame<T, L>) -> InnerJoinIter<'a, T, L> {
         InnerJoin::new(self.df_iter(UtahAxis::Row),
                        other.df_iter(UtahAxis::Row),
                        self.columns.clone(),
@@ -103,7 +214,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
     }
 
     /// Perform an outer left join between two dataframes along the specified `UtahAxis`.
-    fn outer_left_join(&'a self, other: &'a DataFrame<T>) -> OuterJoinIter<'a, T> {
+    fn outer_left_join(&'a self, other: &'a DataFrame<T, L>) -> OuterJoinIter<'a, T, L> {
 
         OuterJoin::new(self.df_iter(UtahAxis::Row),
                        other.df_iter(UtahAxis::Row),
@@ -112,7 +223,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
     }
 
     /// Perform an inner right join between two dataframes along the specified `UtahAxis`.
-    fn inner_right_join(&'a self, other: &'a DataFrame<T>) -> InnerJoinIter<'a, T> {
+    fn inner_right_join(&'a self, other: &'a DataFrame<T, L>) -> InnerJoinIter<'a, T, L> {
         InnerJoin::new(other.df_iter(UtahAxis::Row),
                        self.df_iter(UtahAxis::Row),
                        other.columns.clone(),
@@ -121,7 +232,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
     }
 
     /// Perform an outer right join between two dataframes along the specified `UtahAxis`.
-    fn outer_right_join(&'a self, other: &'a DataFrame<T>) -> OuterJoinIter<'a, T> {
+    fn outer_right_join(&'a self, other: &'a DataFrame<T, L>) -> OuterJoinIter<'a, T, L> {
         OuterJoin::new(other.df_iter(UtahAxis::Row),
                        self.df_iter(UtahAxis::Row),
                        other.columns.clone(),
@@ -129,7 +240,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
 
     }
 
-    fn concat(&'a self, other: &'a DataFrame<T>, axis: UtahAxis) -> ConcatIter<'a, T> {
+    fn concat(&'a self, other: &'a DataFrame<T, L>, axis: UtahAxis) -> ConcatIter<'a, T, L> {
         match axis {
             UtahAxis::Row => {
                 Concat::new(self.df_iter(UtahAxis::Column),
@@ -148,7 +259,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
 
 
     /// Sum along the specified `UtahAxis`.
-    fn sumdf(&'a mut self, axis: UtahAxis) -> SumIter<'a, T> {
+    fn sumdf(&'a mut self, axis: UtahAxis) -> SumIter<'a, T, L> {
         let columns = self.columns.clone();
         let index = self.index.clone();
         match axis {
@@ -159,7 +270,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
     }
 
     /// Map a function along the specified `UtahAxis`.
-    fn mapdf<F>(&'a mut self, f: F, axis: UtahAxis) -> MapDFIter<'a, T, F>
+    fn mapdf<F>(&'a mut self, f: F, axis: UtahAxis) -> MapDFIter<'a, T, F, L>
         where F: Fn(T) -> T
     {
         let columns = self.columns.clone();
@@ -176,7 +287,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
         }
     }
     /// Get the average of entries along the specified `UtahAxis`.
-    fn mean(&'a mut self, axis: UtahAxis) -> MeanIter<'a, T> {
+    fn mean(&'a mut self, axis: UtahAxis) -> MeanIter<'a, T, L> {
 
         let columns = self.columns.clone();
         let index = self.index.clone();
@@ -190,7 +301,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
     }
 
     /// Get the maximum of entries along the specified `UtahAxis`.
-    fn maxdf(&'a mut self, axis: UtahAxis) -> MaxIter<'a, T> {
+    fn maxdf(&'a mut self, axis: UtahAxis) -> MaxIter<'a, T, L> {
 
         let columns = self.columns.clone();
         let index = self.index.clone();
@@ -202,7 +313,7 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
     }
 
     /// Get the minimum of entries along the specified `UtahAxis`.
-    fn mindf(&'a mut self, axis: UtahAxis) -> MinIter<'a, T> {
+    fn mindf(&'a mut self, axis: UtahAxis) -> MinIter<'a, T, L> {
 
         let columns = self.columns.clone();
         let index = self.index.clone();
@@ -214,8 +325,129 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
 
     }
 
+    /// Count the non-empty entries along the specified `UtahAxis`.
+    fn countdf(&'a self, axis: UtahAxis) -> CountIter<'a, T, L> {
+        Count::new(self.df_iter(axis), axis)
+    }
+
+    /// Get the product of non-empty entries along the specified `UtahAxis`.
+    fn prod(&'a self, axis: UtahAxis) -> ProdIter<'a, T, L> {
+        Prod::new(self.df_iter(axis), axis)
+    }
+
+    /// Get the median of non-empty entries along the specified `UtahAxis`.
+    fn median(&'a self, axis: UtahAxis) -> MedianIter<'a, T, L> {
+        Median::new(self.df_iter(axis), axis)
+    }
+
+    /// Get the `q`-th quantile of non-empty entries along the specified `UtahAxis`,
+    /// interpolating between data points as specified by `interpolation`.
+    fn quantile(&'a self,
+                q: f64,
+                interpolation: Interpolation,
+                axis: UtahAxis)
+                -> Result<QuantileIter<'a, T, L>> {
+        Quantile::new(self.df_iter(axis), q, interpolation, axis)
+    }
+
+    /// Get the sample variance of non-empty entries along the specified `UtahAxis`.
+    fn var(&'a self, axis: UtahAxis) -> VarIter<'a, T, L> {
+        Var::new(self.df_iter(axis), axis)
+    }
+
+    /// Get the most frequent non-empty entry along the specified `UtahAxis`.
+    fn mode(&'a self, axis: UtahAxis) -> ModeIter<'a, T, L> {
+        Mode::new(self.df_iter(axis), axis)
+    }
+
+    /// Count the distinct non-empty entries along the specified `UtahAxis`.
+    fn nunique(&'a self, axis: UtahAxis) -> NUniqueIter<'a, T, L> {
+        NUnique::new(self.df_iter(axis), axis)
+    }
+
+    /// Get the label of the maximum entry along the specified `UtahAxis`. Ties resolve to the
+    /// first occurrence, and windows with no non-empty entries yield `None`.
+    fn idxmax(&'a self, axis: UtahAxis) -> IdxMaxIter<'a, T, L> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => IdxMax::new(self.df_iter(UtahAxis::Row), columns, UtahAxis::Row),
+            UtahAxis::Column => {
+                IdxMax::new(self.df_iter(UtahAxis::Column), index, UtahAxis::Column)
+            }
+
+        }
+    }
+
+    /// Get the label of the minimum entry along the specified `UtahAxis`. Ties resolve to the
+    /// first occurrence, and windows with no non-empty entries yield `None`.
+    fn idxmin(&'a self, axis: UtahAxis) -> IdxMinIter<'a, T, L> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => IdxMin::new(self.df_iter(UtahAxis::Row), columns, UtahAxis::Row),
+            UtahAxis::Column => {
+                IdxMin::new(self.df_iter(UtahAxis::Column), index, UtahAxis::Column)
+            }
+
+        }
+    }
+
+    /// Shift the entries of every row or column by `n` positions along the specified
+    /// `UtahAxis`. A positive `n` lags the entries, a negative one leads them, and the vacated
+    /// positions are left empty.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let res = df.shift(1, UtahAxis::Column).as_df().unwrap();
+    /// assert_eq!(res.data[[2, 0]], 3.0);
+    /// ```
+    fn shift(&'a self, n: isize, axis: UtahAxis) -> ShiftIter<'a, T, L> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => Shift::new(self.df_iter(UtahAxis::Row), n, columns, UtahAxis::Row),
+            UtahAxis::Column => {
+                Shift::new(self.df_iter(UtahAxis::Column), n, index, UtahAxis::Column)
+            }
+
+        }
+    }
+
+    /// Get the difference between every entry and the entry `n` positions before it along the
+    /// specified `UtahAxis`.
+    fn diff(&'a self, n: isize, axis: UtahAxis) -> DiffIter<'a, T, L> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => Diff::new(self.df_iter(UtahAxis::Row), n, columns, UtahAxis::Row),
+            UtahAxis::Column => {
+                Diff::new(self.df_iter(UtahAxis::Column), n, index, UtahAxis::Column)
+            }
+
+        }
+    }
+
+    /// Get the relative change between every entry and the entry `n` positions before it along
+    /// the specified `UtahAxis`. Changes from zero are left empty.
+    fn pct_change(&'a self, n: isize, axis: UtahAxis) -> PctChangeIter<'a, T, L> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => {
+                PctChange::new(self.df_iter(UtahAxis::Row), n, columns, UtahAxis::Row)
+            }
+            UtahAxis::Column => {
+                PctChange::new(self.df_iter(UtahAxis::Column), n, index, UtahAxis::Column)
+            }
+
+        }
+    }
+
     /// Replace empty values with specified ImputeStrategy along the specified `UtahAxis`.
-    fn impute(&'a mut self, strategy: ImputeStrategy, axis: UtahAxis) -> ImputeIter<'a, T> {
+    fn impute(&'a mut self, strategy: ImputeStrategy, axis: UtahAxis) -> ImputeIter<'a, T, L> {
 
         let index = self.index.clone();
         let columns = self.columns.clone();
@@ -235,6 +467,109 @@ impl<'a, T> Operations<'a, T> for DataFrame<T>
 
         }
     }
+
+    /// Replace each entry with the cumulative sum of the entries before it, running along
+    /// every row (`UtahAxis::Row`) or column (`UtahAxis::Column`). Empty entries are kept
+    /// empty and do not reset the running sum.
+    fn cumsum(&'a mut self, axis: UtahAxis) -> CumSumIter<'a, T, L> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => CumSum::new(self.df_iter_mut(UtahAxis::Row), columns, UtahAxis::Row),
+            UtahAxis::Column => {
+                CumSum::new(self.df_iter_mut(UtahAxis::Column), index, UtahAxis::Column)
+            }
+
+        }
+    }
+
+    /// Replace each entry with the cumulative product of the entries before it, running along
+    /// every row (`UtahAxis::Row`) or column (`UtahAxis::Column`). Empty entries are kept
+    /// empty and do not reset the running product.
+    fn cumprod(&'a mut self, axis: UtahAxis) -> CumProdIter<'a, T, L> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => CumProd::new(self.df_iter_mut(UtahAxis::Row), columns, UtahAxis::Row),
+            UtahAxis::Column => {
+                CumProd::new(self.df_iter_mut(UtahAxis::Column), index, UtahAxis::Column)
+            }
+
+        }
+    }
+
+    /// Replace each entry with the cumulative maximum of the entries before it, running along
+    /// every row (`UtahAxis::Row`) or column (`UtahAxis::Column`). Empty entries are kept
+    /// empty and do not reset the running maximum.
+    fn cummax(&'a mut self, axis: UtahAxis) -> CumMaxIter<'a, T, L> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => CumMax::new(self.df_iter_mut(UtahAxis::Row), columns, UtahAxis::Row),
+            UtahAxis::Column => {
+                CumMax::new(self.df_iter_mut(UtahAxis::Column), index, UtahAxis::Column)
+            }
+
+        }
+    }
+
+    /// Replace each entry with the cumulative minimum of the entries before it, running along
+    /// every row (`UtahAxis::Row`) or column (`UtahAxis::Column`). Empty entries are kept
+    /// empty and do not reset the running minimum.
+    fn cummin(&'a mut self, axis: UtahAxis) -> CumMinIter<'a, T, L> {
+        let columns = self.columns.clone();
+        let index = self.index.clone();
+        match axis {
+            UtahAxis::Row => CumMin::new(self.df_iter_mut(UtahAxis::Row), columns, UtahAxis::Row),
+            UtahAxis::Column => {
+                CumMin::new(self.df_iter_mut(UtahAxis::Column), index, UtahAxis::Column)
+            }
+
+        }
+    }
+
+    /// Create a rolling window of `window` rows that slides down the index. Positions whose
+    /// window holds fewer than `min_periods` non-empty entries are left empty. If `center` is
+    /// set, each window is centered on its row rather than ending at it.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let res = df.rolling(2, 2, false).sum().unwrap();
+    /// assert_eq!(res.data[[2, 0]], 8.0);
+    /// ```
+    fn rolling(&'a self, window: usize, min_periods: usize, center: bool) -> Rolling<'a, T, L> {
+        Rolling::new(self, window, min_periods, center)
+    }
+
+    /// Create an expanding window, which covers every row up to and including the current
+    /// one. Positions with fewer than `min_periods` non-empty entries so far are left empty.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let res = df.expanding(1).mean().unwrap();
+    /// assert_eq!(res.data[[2, 0]], 3.0);
+    /// ```
+    fn expanding(&'a self, min_periods: usize) -> Expanding<'a, T, L> {
+        Expanding::new(self, min_periods)
+    }
+
+    /// Create an exponentially weighted window, whose decay is given as a smoothing factor,
+    /// span or half-life.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let res = df.ewm(Decay::Alpha(0.5), false).mean().unwrap();
+    /// assert_eq!(res.data[[1, 0]], 2.0);
+    /// ```
+    fn ewm(&'a self, decay: Decay, adjust: bool) -> Ewm<'a, T, L> {
+        Ewm::new(self, decay, adjust)
+    }
 }
 
 
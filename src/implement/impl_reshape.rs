@@ -0,0 +1,201 @@
+use util::types::*;
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use mixedtypes::{InnerType, OuterType};
+use ndarray::Array;
+use std::collections::HashMap;
+use std::cmp::Ordering;
+use implement::impl_duplicates::column_position;
+use implement::impl_multiindex::label;
+use multiindex::*;
+
+impl<T, L> Reshape<T> for DataFrame<T, L>
+    where T: UtahNum + ToHashKey,
+          L: UtahLabel
+{
+    /// Reshape from long to wide form: the distinct values of the `index` column become the
+    /// index, the distinct values of the `columns` column become the columns, and each cell
+    /// holds the matching entry of the `values` column. Both axes are single-level
+    /// multi-indexes named after their columns, whose labels keep the type of the values.
+    /// Labels appear in order of first occurrence, and cells without an entry are left empty.
+    /// Two entries for the same cell are an error; use `pivot_table` to aggregate them instead.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 1.0, 5.0], [1.0, 2.0, 6.0], [2.0, 1.0, 7.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["day", "site", "temp"]).unwrap();
+    /// let res = df.pivot("day", "site", "temp").unwrap();
+    /// assert_eq!(res.data[[0, 1]], 6.0);
+    /// assert_eq!(res.columns.level_values(0), vec![OuterType::from(1.0), OuterType::from(2.0)]);
+    /// ```
+    fn pivot(&self, index: &str, columns: &str, values: &str) -> Result<MultiIndexFrame<T>> {
+        let cells = group_cells(self, index, columns, values)?;
+        if let Some((r, c)) = cells.duplicate {
+            return Err(ErrorKind::DuplicateEntry(cells.index[r].to_string(),
+                                                 cells.columns[c].to_string())
+                .into());
+        }
+        let mut d = Array::from_elem((cells.index.len(), cells.columns.len()), T::empty());
+        for (&(r, c), entries) in &cells.groups {
+            d[[r, c]] = entries[0].clone();
+        }
+        cells.into_frame(d, index, columns)
+    }
+
+    /// Like `pivot`, but the entries that share a cell are reduced with the aggregate
+    /// combinator given by `aggfunc`. Cells without an entry hold `fill_value`, or are left
+    /// empty.
+    fn pivot_table(&self,
+                   index: &str,
+                   columns: &str,
+                   values: &str,
+                   aggfunc: AggFunc,
+                   fill_value: Option<T>)
+                   -> Result<MultiIndexFrame<T>>
+        where T: PartialOrd
+    {
+        let cells = group_cells(self, index, columns, values)?;
+        let fill = fill_value.unwrap_or(T::empty());
+        let mut d = Array::from_elem((cells.index.len(), cells.columns.len()), fill);
+        for (&(r, c), entries) in &cells.groups {
+            d[[r, c]] = aggregate(entries.clone(), aggfunc);
+        }
+        cells.into_frame(d, index, columns)
+    }
+
+    /// Reshape from wide to long form. Every entry of the `value_vars` columns (by default,
+    /// all columns not in `id_vars`) becomes a row holding the `id_vars` of its row, the name of
+    /// its column under `variable`, and the entry itself under `value`.
+    fn melt(&self, id_vars: &[&str], value_vars: Option<&[&str]>) -> Result<DataFrame<InnerType>>
+        where InnerType: From<T>
+    {
+        let ids: Vec<usize> = id_vars.iter()
+            .map(|x| column_position(self, x))
+            .collect::<Result<_>>()?;
+        let vars: Vec<usize> = match value_vars {
+            Some(names) => names.iter().map(|x| column_position(self, x)).collect::<Result<_>>()?,
+            None => (0..self.columns.len()).filter(|j| !ids.contains(j)).collect(),
+        };
+
+        let mut c = Vec::with_capacity(self.index.len() * vars.len() * (ids.len() + 2));
+        for &v in &vars {
+            for row in self.data.outer_iter() {
+                c.extend(ids.iter().map(|&j| InnerType::from(row[j].clone())));
+                c.push(InnerType::Str(self.columns[v].to_string()));
+                c.push(InnerType::from(row[v].clone()));
+            }
+        }
+
+        let mut names: Vec<&str> = id_vars.to_vec();
+        names.push("variable");
+        names.push("value");
+        let d = Array::from_shape_vec((self.index.len() * vars.len(), names.len()), c).unwrap();
+        DataFrame::new(d).columns(&names[..])
+    }
+}
+
+/// Reduce a group of entries with the aggregate combinator given by `aggfunc`.
+pub fn aggregate<T>(entries: Vec<T>, aggfunc: AggFunc) -> T
+    where T: UtahNum + PartialOrd
+{
+    let group: DataFrame<T> = DataFrame::from_array(Array::from_vec(entries), UtahAxis::Column);
+    let windows = group.df_iter(UtahAxis::Column);
+    let res = match aggfunc {
+        AggFunc::Sum => windows.sumdf().next(),
+        AggFunc::Mean => windows.mean().next(),
+        AggFunc::Count => windows.countdf().next(),
+        AggFunc::Prod => windows.prod().next(),
+        AggFunc::Median => windows.median().next(),
+        AggFunc::Var => windows.var().next(),
+        AggFunc::Mode => windows.mode().next(),
+        AggFunc::NUnique => windows.nunique().next(),
+        AggFunc::Max => extremum(group.data.iter(), Ordering::Greater),
+        AggFunc::Min => extremum(group.data.iter(), Ordering::Less),
+    };
+    res.unwrap_or(T::empty())
+}
+
+/// The largest or smallest non-empty entry, as `keep` is `Greater` or `Less`. The `Max` and
+/// `Min` combinators need totally ordered entries, which floats are not.
+fn extremum<'a, T, I>(entries: I, keep: Ordering) -> Option<T>
+    where T: UtahNum + PartialOrd + 'a,
+          I: Iterator<Item = &'a T>
+{
+    let mut res: Option<&T> = None;
+    for x in entries.filter(|x| !x.is_empty()) {
+        if res.map_or(true, |y| x.partial_cmp(y) == Some(keep)) {
+            res = Some(x);
+        }
+    }
+    res.cloned()
+}
+
+/// The entries of a `values` column, grouped by the cell that their `index` and `columns`
+/// entries place them in.
+struct Cells<T> {
+    index: Vec<OuterType>,
+    columns: Vec<OuterType>,
+    groups: HashMap<(usize, usize), Vec<T>>,
+    /// The first cell, in row order, that received a second entry.
+    duplicate: Option<(usize, usize)>,
+}
+
+impl<T> Cells<T>
+    where T: UtahNum
+{
+    /// Label the rows and columns of `data`, which is laid out like the cells, with the
+    /// `index` and `columns` levels.
+    fn into_frame(self,
+                  data: Matrix<T>,
+                  index: &str,
+                  columns: &str)
+                  -> Result<MultiIndexFrame<T>> {
+        let to_keys = |labels: Vec<OuterType>| labels.into_iter().map(|x| vec![x]).collect();
+        MultiIndexFrame::new(data,
+                             MultiIndex::new(&[index], to_keys(self.index))?,
+                             MultiIndex::new(&[columns], to_keys(self.columns))?)
+    }
+}
+
+fn group_cells<T, L>(df: &DataFrame<T, L>,
+                     index: &str,
+                     columns: &str,
+                     values: &str)
+                     -> Result<Cells<T>>
+    where T: UtahNum + ToHashKey,
+          L: UtahLabel
+{
+    let (i, j, v) = (column_position(df, index)?,
+                     column_position(df, columns)?,
+                     column_position(df, values)?);
+    let mut rows: HashMap<HashKey, usize> = HashMap::new();
+    let mut cols: HashMap<HashKey, usize> = HashMap::new();
+    let mut cells = Cells {
+        index: Vec::new(),
+        columns: Vec::new(),
+        groups: HashMap::new(),
+        duplicate: None,
+    };
+    for row in df.data.outer_iter() {
+        let (r, c) = (row[i].hash_key(), row[j].hash_key());
+        // Entries without a label have nowhere to go.
+        if r == HashKey::Empty || c == HashKey::Empty {
+            continue;
+        }
+        let r = *rows.entry(r.clone()).or_insert_with(|| {
+            cells.index.push(label(&r));
+            cells.index.len() - 1
+        });
+        let c = *cols.entry(c.clone()).or_insert_with(|| {
+            cells.columns.push(label(&c));
+            cells.columns.len() - 1
+        });
+        let entries = cells.groups.entry((r, c)).or_insert_with(Vec::new);
+        if !entries.is_empty() && cells.duplicate.is_none() {
+            cells.duplicate = Some((r, c));
+        }
+        entries.push(row[v].clone());
+    }
+    Ok(cells)
+}
@@ -0,0 +1,153 @@
+use util::types::*;
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use ndarray::Array;
+use rand::{Rng, SeedableRng, StdRng};
+use mixedtypes::InnerType;
+use implement::impl_duplicates::column_position;
+
+/// A random number generator whose sequence is fully determined by `seed`.
+pub fn seeded_rng(seed: u64) -> StdRng {
+    let seed = [seed as usize, (seed >> 32) as usize];
+    StdRng::from_seed(&seed[..])
+}
+
+impl<T, L> Sample<T, L> for DataFrame<T, L>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    /// Draw a random sample of rows, either a fixed number of them or a fraction of the
+    /// dataframe. Without replacement, each row is drawn at most once.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    /// let res = df.sample(SampleSize::N(2), false, 42).unwrap();
+    /// assert_eq!(res, df.sample(SampleSize::N(2), false, 42).unwrap());
+    /// ```
+    fn sample(&self, size: SampleSize, replace: bool, seed: u64) -> Result<DataFrame<T, L>> {
+        let nrows = self.index.len();
+        let n = match size {
+            SampleSize::N(n) => n,
+            SampleSize::Frac(f) if f >= 0. => (f * nrows as f64).round() as usize,
+            SampleSize::Frac(f) => {
+                return Err(ErrorKind::InvalidSampleSize(format!("negative fraction {}", f))
+                    .into())
+            }
+        };
+        if n > 0 && nrows == 0 || n > nrows && !replace {
+            return Err(ErrorKind::InvalidSampleSize(format!("cannot draw {} of {} rows \
+                                                             without replacement",
+                                                            n,
+                                                            nrows))
+                .into());
+        }
+
+        let mut rng = seeded_rng(seed);
+        let positions: Vec<usize> = if replace {
+            (0..n).map(|_| rng.gen_range(0, nrows)).collect()
+        } else {
+            let mut positions: Vec<usize> = (0..nrows).collect();
+            rng.shuffle(&mut positions[..]);
+            positions.truncate(n);
+            positions
+        };
+        self.take(&positions[..], UtahAxis::Row)
+    }
+
+    /// Randomly reorder the rows of the dataframe, keeping each row with its label.
+    fn shuffle(&self, seed: u64) -> DataFrame<T, L> {
+        let mut positions: Vec<usize> = (0..self.index.len()).collect();
+        seeded_rng(seed).shuffle(&mut positions[..]);
+        self.take(&positions[..], UtahAxis::Row).unwrap()
+    }
+
+    /// Randomly split the rows into a training set holding `frac` of them and a test set
+    /// holding the rest. With `stratify_on`, the split is made separately for every value of
+    /// that column, so both sets keep its proportions.
+    fn train_test_split(&self,
+                        frac: f64,
+                        stratify_on: Option<&str>,
+                        seed: u64)
+                        -> Result<(DataFrame<T, L>, DataFrame<T, L>)> {
+        if !(0. ..=1.).contains(&frac) {
+            return Err(ErrorKind::InvalidSampleSize(format!("fraction {} is not between 0 and \
+                                                             1",
+                                                            frac))
+                .into());
+        }
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        match stratify_on {
+            None => groups.push((0..self.index.len()).collect()),
+            Some(name) => {
+                let j = column_position(self, name)?;
+                // Empty values cannot be compared, so they are gathered into a group of their
+                // own.
+                let mut keys: Vec<Option<T>> = Vec::new();
+                for (i, x) in self.data.column(j).iter().enumerate() {
+                    let key = if x.is_empty() { None } else { Some(x.clone()) };
+                    match keys.iter().position(|k| *k == key) {
+                        Some(g) => groups[g].push(i),
+                        None => {
+                            keys.push(key);
+                            groups.push(vec![i]);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut rng = seeded_rng(seed);
+        let (mut train, mut test) = (Vec::new(), Vec::new());
+        for mut group in groups {
+            rng.shuffle(&mut group[..]);
+            let n = (frac * group.len() as f64).round() as usize;
+            train.extend_from_slice(&group[..n]);
+            test.extend_from_slice(&group[n..]);
+        }
+        rng.shuffle(&mut train[..]);
+        rng.shuffle(&mut test[..]);
+        Ok((self.take(&train[..], UtahAxis::Row)?, self.take(&test[..], UtahAxis::Row)?))
+    }
+}
+
+impl RandomMixed for DataFrame<InnerType> {
+    /// Generate a mixed-type dataframe for fuzzing. Every column gets a random type out of
+    /// floats, 64-bit and 32-bit integers, and short strings, and each cell is independently
+    /// left empty with probability `empty_frac`.
+    fn random_mixed(shape: (usize, usize),
+                    empty_frac: f64,
+                    seed: u64)
+                    -> Result<DataFrame<InnerType>> {
+        if !(0. ..=1.).contains(&empty_frac) {
+            return Err(ErrorKind::InvalidSampleSize(format!("fraction {} of empty cells is not \
+                                                             between 0 and 1",
+                                                            empty_frac))
+                .into());
+        }
+        let mut rng = seeded_rng(seed);
+        let kinds: Vec<u8> = (0..shape.1).map(|_| rng.gen_range(0, 4)).collect();
+        let mut c = Vec::with_capacity(shape.0 * shape.1);
+        for _ in 0..shape.0 {
+            for kind in &kinds {
+                if rng.gen::<f64>() < empty_frac {
+                    c.push(InnerType::Empty);
+                    continue;
+                }
+                c.push(match *kind {
+                    0 => InnerType::Float(rng.gen_range(-1000., 1000.)),
+                    1 => InnerType::Int64(rng.gen_range(-1000, 1000)),
+                    2 => InnerType::Int32(rng.gen_range(-1000, 1000)),
+                    _ => {
+                        let len = rng.gen_range(1, 9);
+                        InnerType::Str(rng.gen_ascii_chars().take(len).collect())
+                    }
+                });
+            }
+        }
+        Ok(DataFrame::new(Array::from_shape_vec(shape, c).unwrap()))
+    }
+}
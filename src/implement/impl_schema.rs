@@ -0,0 +1,177 @@
+use util::error::*;
+use util::traits::*;
+use dataframe::*;
+use columnar::ColumnFrame;
+use schema::{Field, Schema};
+use mixedtypes::DType;
+use ndarray::Axis;
+
+macro_rules! impl_float_dtype {
+    ($($t:ty => $dtype:expr),*) => {
+        $(impl ToDType for $t {
+            fn element_dtype() -> Option<DType> {
+                Some($dtype)
+            }
+            fn dtype(&self) -> Option<DType> {
+                if self.is_nan() { None } else { Some($dtype) }
+            }
+        })*
+    }
+}
+
+// Integers have no value of their own for empty entries, so none of them are empty here.
+macro_rules! impl_integer_dtype {
+    ($($t:ty => $dtype:expr),*) => {
+        $(impl ToDType for $t {
+            fn element_dtype() -> Option<DType> {
+                Some($dtype)
+            }
+            fn dtype(&self) -> Option<DType> {
+                Some($dtype)
+            }
+        })*
+    }
+}
+
+impl_float_dtype!(f64 => DType::Float, f32 => DType::Float32);
+impl_integer_dtype!(i64 => DType::Int64, i32 => DType::Int32, u64 => DType::UInt64);
+
+/// The name of a column, the type of its element type if it has only one, and the type of each
+/// of its entries.
+struct Profile {
+    name: String,
+    dtype: Option<DType>,
+    entries: Vec<Option<DType>>,
+}
+
+impl<T, L> ToSchema for DataFrame<T, L>
+    where T: UtahNum + ToDType,
+          L: UtahLabel
+{
+    /// The schema that this dataframe satisfies. A column has the type of its element type, or
+    /// else of its non-empty entries if they all have the same type, and is nullable if it
+    /// holds an empty entry.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[InnerType::from("ann"), InnerType::Int64(31)],
+    ///                [InnerType::from("bob"), InnerType::Empty]]);
+    /// let df: DataFrame<InnerType> = DataFrame::new(a).columns(&["name", "age"]).unwrap();
+    /// let schema = df.schema();
+    /// let expected = Schema::new().field("name", DType::Str, false).field("age", DType::Int64, true);
+    /// assert_eq!(schema, expected);
+    /// assert!(df.validate(&schema).is_ok());
+    /// ```
+    fn schema(&self) -> Schema {
+        schema_of(&self.profile())
+    }
+
+    /// Check that this dataframe has exactly the columns of `schema`, in any order, and that
+    /// their entries have the right types and are only empty where they may be.
+    fn validate(&self, schema: &Schema) -> Result<()> {
+        let index: Vec<String> = self.index.iter().map(|x| x.to_string()).collect();
+        validate(&self.profile(), &index[..], schema)
+    }
+}
+
+impl<T, L> DataFrame<T, L>
+    where T: UtahNum + ToDType,
+          L: UtahLabel
+{
+    fn profile(&self) -> Vec<Profile> {
+        self.columns
+            .iter()
+            .zip(self.data.axis_iter(Axis(1)))
+            .map(|(name, column)| {
+                Profile {
+                    name: name.to_string(),
+                    dtype: T::element_dtype(),
+                    entries: column.iter().map(|x| x.dtype()).collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl ToSchema for ColumnFrame {
+    /// The schema that this frame satisfies. A column has the type of its entries, and is
+    /// nullable if it holds an empty entry.
+    fn schema(&self) -> Schema {
+        schema_of(&self.profile())
+    }
+
+    /// Check that this frame has exactly the columns of `schema`, in any order, with the right
+    /// types, and that they are only empty where they may be.
+    fn validate(&self, schema: &Schema) -> Result<()> {
+        validate(&self.profile(), &self.index[..], schema)
+    }
+}
+
+impl ColumnFrame {
+    fn profile(&self) -> Vec<Profile> {
+        self.columns
+            .iter()
+            .zip(self.data.iter())
+            .map(|(name, column)| {
+                Profile {
+                    name: name.clone(),
+                    dtype: Some(column.dtype()),
+                    entries: column.to_inner().iter().map(|x| x.dtype()).collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+fn schema_of(profile: &[Profile]) -> Schema {
+    let fields = profile.iter()
+        .map(|column| {
+            let dtype = column.dtype.or_else(|| {
+                let mut types = column.entries.iter().filter_map(|x| *x);
+                types.next().and_then(|first| if types.all(|x| x == first) {
+                    Some(first)
+                } else {
+                    None
+                })
+            });
+            Field {
+                name: column.name.clone(),
+                dtype,
+                nullable: column.entries.iter().any(|x| x.is_none()),
+            }
+        })
+        .collect();
+    Schema { fields }
+}
+
+fn validate(profile: &[Profile], index: &[String], schema: &Schema) -> Result<()> {
+    let names: Vec<&str> = profile.iter().map(|x| &x.name[..]).collect();
+    if let Some(field) = schema.fields.iter().find(|f| !names.contains(&&f.name[..])) {
+        return Err(ErrorKind::MissingColumn(field.name.clone()).into());
+    }
+    if let Some(column) = profile.iter().find(|c| schema.get(&c.name).is_none()) {
+        return Err(ErrorKind::ExtraColumn(column.name.clone()).into());
+    }
+    for column in profile {
+        let field = schema.get(&column.name).unwrap();
+        if let Some(expected) = field.dtype {
+            let actual = column.dtype
+                .into_iter()
+                .chain(column.entries.iter().filter_map(|x| *x))
+                .find(|&x| x != expected);
+            if let Some(actual) = actual {
+                return Err(ErrorKind::ColumnTypeMismatch(column.name.clone(),
+                                                         expected.to_string(),
+                                                         actual.to_string())
+                    .into());
+            }
+        }
+        if !field.nullable {
+            if let Some(i) = column.entries.iter().position(|x| x.is_none()) {
+                return Err(ErrorKind::UnexpectedEmpty(column.name.clone(), index[i].clone())
+                    .into());
+            }
+        }
+    }
+    Ok(())
+}
@@ -3,3 +3,16 @@ pub mod impl_constructor_generic;
 pub mod impl_ops_f64_string;
 pub mod impl_ops_generic;
 pub mod impl_empty;
+pub mod impl_label;
+pub mod impl_describe;
+pub mod impl_correlation;
+pub mod impl_sample;
+pub mod impl_duplicates;
+pub mod impl_frequency;
+pub mod impl_reshape;
+pub mod impl_multiindex;
+pub mod impl_datetime;
+pub mod impl_categorical;
+pub mod impl_cast;
+pub mod impl_schema;
+pub mod impl_modify;
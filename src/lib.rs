@@ -57,6 +57,18 @@
 //! ```
 //!
 //!
+//! ### Lag combinators
+//!
+//! Lag combinators compare every entry of a row or column with the entries before it, which is handy for building lagged features. Combinators in this class include `shift`, `diff` and `pct_change`. Positions without a counterpart are left empty.
+//!
+//! ```ignore
+//! use utah::prelude::*;
+//! let a = arr2(&[[2.0, 7.0], [3.0, 4.0], [2.0, 8.0]]);
+//! let df : DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+//! let res = df.df_iter(UtahAxis::Column).select(&["a"]).diff(1).as_df();
+//! ```
+//!
+//!
 //! ### Process combinators
 //!
 //! Process combinators are meant for changing the original data you're working with. Combinators in this class include `impute` and `mapdf`. Impute replaces missing values of a dataframe with the mean of the corresponding column. Not that these operations require the use of a `DataFrameMut`.
@@ -92,7 +104,7 @@
 //!
 //! ### Aggregate combinators
 //!
-//! Aggregate combinators are meant for reduction of a chain of combinators to some result. They are usually the last operation in a chain, but don't necessarily have to be. Combinators in this class include `sumdf`, `mindf`, `maxdf`, `stdev` (standard deviation), and `mean`. Currently, aggregate combinators are not iterator collection operations, because they do not invoke an iterator chain. This may change in the future.
+//! Aggregate combinators are meant for reduction of a chain of combinators to some result. They are usually the last operation in a chain, but don't necessarily have to be. Combinators in this class include `sumdf`, `mindf`, `maxdf`, `stdev` (standard deviation), `mean`, `countdf`, `prod`, `median`, `quantile`, `var` (sample variance), `mode`, and `nunique`. Empty values are skipped by the reducers that count, order or multiply entries. Currently, aggregate combinators are not iterator collection operations, because they do not invoke an iterator chain. This may change in the future.
 //!
 //! ```ignore
 //! let a = arr2(&[[2.0, 7.0], [3.0, 4.0], [2.0, 8.0]]);
@@ -143,7 +155,11 @@
 //!    Float(f64),
 //!    Int64(i64),
 //!    Int32(i32),
+//!    Float32(f32),
+//!    UInt64(u64),
+//!    Bool(bool),
 //!    Str(String),
+//!    Datetime(Datetime),
 //!    Empty,
 //! }
 //! ```
@@ -156,9 +172,15 @@
 //!    Int64(i64),
 //!    Int32(i32),
 //!    USize(usize),
+//!    Datetime(Datetime),
+//!    Float(FloatLabel),
+//!    UInt64(u64),
+//!    Bool(bool),
 //! }
 //! ```
 //!
+//! Integer labels are the same label whatever their width, so a row labeled `USize(1)` can be
+//! selected with `1i64`.
 //!
 //! With these wrappers, you can have Strings and f64s in the same dataframe.
 //!
@@ -196,6 +218,11 @@ pub mod dataframe;
 pub mod util;
 mod implement;
 pub mod mixedtypes;
+pub mod datetime;
+pub mod categorical;
+pub mod columnar;
+pub mod schema;
+pub mod multiindex;
 mod bench;
 #[macro_use]
 mod tests;
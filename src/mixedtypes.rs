@@ -2,18 +2,132 @@
 
 use std::ops::{Mul, Add, Sub, Div};
 use std::cmp::Ordering;
-use num::traits::{One, Zero};
-use util::traits::Empty;
-use std::f64::NAN;
+use num::traits::{One, Zero, FromPrimitive, ToPrimitive};
+use util::traits::{Empty, ToHashKey, ToDType};
+use util::types::HashKey;
 use std::str::FromStr;
+use std::fmt;
+use std::mem;
+use std::hash::{Hash, Hasher};
 use util::error::ErrorKind;
+use datetime::Datetime;
+use rustc_serialize::{Decodable, Decoder};
 
-#[derive(RustcDecodable, Hash, PartialOrd, PartialEq, Eq , Ord , Clone,  Debug)]
+/// An axis label. Labels compare and hash like their `HashKey`s, so integer labels of every
+/// width are the same label when they have the same value, and sort by value.
+#[derive(RustcDecodable, Clone,  Debug)]
 pub enum OuterType {
     Str(String),
     Int64(i64),
     Int32(i32),
     USize(usize),
+    Datetime(Datetime),
+    Float(FloatLabel),
+    UInt64(u64),
+    Bool(bool),
+}
+
+/// A float axis label. Labels hash and compare by value, in total order, so that they can key
+/// an index: `-0.0` is the same label as `0.0`, and every NaN is the same label.
+#[derive(Clone, Copy, Debug)]
+pub struct FloatLabel(pub f64);
+
+impl Decodable for FloatLabel {
+    fn decode<D: Decoder>(d: &mut D) -> Result<FloatLabel, D::Error> {
+        d.read_f64().map(FloatLabel)
+    }
+}
+
+impl Hash for FloatLabel {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash_key().hash(state)
+    }
+}
+
+impl PartialEq for FloatLabel {
+    fn eq(&self, other: &FloatLabel) -> bool {
+        self.0.hash_key() == other.0.hash_key()
+    }
+}
+
+impl Eq for FloatLabel {}
+
+impl PartialOrd for FloatLabel {
+    fn partial_cmp(&self, other: &FloatLabel) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for FloatLabel {
+    fn cmp(&self, other: &FloatLabel) -> Ordering {
+        self.0.hash_key().cmp(&other.0.hash_key())
+    }
+}
+
+impl OuterType {
+    /// The key of a numeric label.
+    fn number_key(&self) -> Option<HashKey> {
+        match *self {
+            OuterType::Int64(x) => Some(HashKey::Int(x)),
+            OuterType::Int32(x) => Some(HashKey::Int(x as i64)),
+            OuterType::USize(x) => Some((x as u64).hash_key()),
+            OuterType::UInt64(x) => Some(x.hash_key()),
+            OuterType::Float(x) => Some(x.0.hash_key()),
+            _ => None,
+        }
+    }
+
+    /// Where the label sorts among labels of other kinds.
+    fn rank(&self) -> u8 {
+        match *self {
+            OuterType::Bool(_) => 1,
+            OuterType::Str(_) => 2,
+            OuterType::Datetime(_) => 3,
+            _ => 0,
+        }
+    }
+}
+
+impl Hash for OuterType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+        match *self {
+            OuterType::Bool(x) => x.hash(state),
+            OuterType::Str(ref x) => x.hash(state),
+            OuterType::Datetime(x) => x.hash(state),
+            _ => self.number_key().hash(state),
+        }
+    }
+}
+
+impl PartialEq for OuterType {
+    fn eq(&self, other: &OuterType) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OuterType {}
+
+impl PartialOrd for OuterType {
+    fn partial_cmp(&self, other: &OuterType) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OuterType {
+    fn cmp(&self, other: &OuterType) -> Ordering {
+        match (self, other) {
+            (OuterType::Bool(x), OuterType::Bool(y)) => x.cmp(y),
+            (OuterType::Str(x), OuterType::Str(y)) => x.cmp(y),
+            (OuterType::Datetime(x), OuterType::Datetime(y)) => x.cmp(y),
+            _ => {
+                match (self.number_key(), other.number_key()) {
+                    (Some(x), Some(y)) => x.cmp(&y),
+                    _ => self.rank().cmp(&other.rank()),
+                }
+            }
+        }
+    }
 }
 
 #[derive(RustcDecodable,Clone, Debug)]
@@ -21,19 +135,179 @@ pub enum InnerType {
     Float(f64),
     Int64(i64),
     Int32(i32),
+    Float32(f32),
+    UInt64(u64),
+    Bool(bool),
     Str(String),
+    Datetime(Datetime),
     Empty,
 }
 
+/// The type of the entries of an `InnerType`, without their values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DType {
+    Float,
+    Int64,
+    Int32,
+    Float32,
+    UInt64,
+    Bool,
+    Str,
+    Datetime,
+}
+
+impl InnerType {
+    /// The type of this entry, or `None` if it is empty. NaN floats are empty.
+    pub fn dtype(&self) -> Option<DType> {
+        match *self {
+            InnerType::Float(x) if x.is_nan() => None,
+            InnerType::Float32(x) if x.is_nan() => None,
+            InnerType::Float(_) => Some(DType::Float),
+            InnerType::Int64(_) => Some(DType::Int64),
+            InnerType::Int32(_) => Some(DType::Int32),
+            InnerType::Float32(_) => Some(DType::Float32),
+            InnerType::UInt64(_) => Some(DType::UInt64),
+            InnerType::Bool(_) => Some(DType::Bool),
+            InnerType::Str(_) => Some(DType::Str),
+            InnerType::Datetime(_) => Some(DType::Datetime),
+            InnerType::Empty => None,
+        }
+    }
+
+    /// Convert this entry to an entry of type `dtype`, or `None` if it has no such value.
+    /// Strings are parsed, numbers are truncated towards zero when cast to integers, only `0`
+    /// and `1` are booleans, and integers are timestamps when cast to datetimes.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// assert_eq!(InnerType::from("42").to_dtype(DType::Int32), Some(InnerType::Int32(42)));
+    /// assert_eq!(InnerType::Float(2.5).to_dtype(DType::Str), Some(InnerType::from("2.5")));
+    /// assert_eq!(InnerType::from("n/a").to_dtype(DType::Float), None);
+    /// ```
+    pub fn to_dtype(&self, dtype: DType) -> Option<InnerType> {
+        if let InnerType::Str(ref s) = *self {
+            return match dtype {
+                DType::Str => Some(self.clone()),
+                DType::Datetime => s.parse().ok().map(InnerType::Datetime),
+                _ => {
+                    match s.parse::<InnerType>() {
+                        Ok(InnerType::Str(_)) | Err(_) => None,
+                        Ok(x) => x.to_dtype(dtype),
+                    }
+                }
+            };
+        }
+        match dtype {
+            DType::Float => self.to_f64().map(InnerType::Float),
+            DType::Int64 => self.to_i64().map(InnerType::Int64),
+            DType::Int32 => self.to_i32().map(InnerType::Int32),
+            DType::Float32 => self.to_f32().map(InnerType::Float32),
+            DType::UInt64 => self.to_u64().map(InnerType::UInt64),
+            DType::Bool => {
+                match self.to_i64() {
+                    Some(0) => Some(InnerType::Bool(false)),
+                    Some(1) => Some(InnerType::Bool(true)),
+                    _ => None,
+                }
+            }
+            DType::Str => self.hash_key().to_label().map(|x| InnerType::Str(x.to_string())),
+            DType::Datetime => {
+                match *self {
+                    InnerType::Datetime(d) => Some(InnerType::Datetime(d)),
+                    _ => self.to_i64().map(|x| InnerType::Datetime(Datetime::from_timestamp(x))),
+                }
+            }
+        }
+    }
+
+    /// Like `to_dtype`, but only for conversions that keep the value: numbers must convert
+    /// back to themselves, so `1.5` is not an integer, and booleans are not numbers.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// assert_eq!(InnerType::Float(2.0).to_dtype_exact(DType::Int64), Some(InnerType::Int64(2)));
+    /// assert_eq!(InnerType::from("1.5").to_dtype_exact(DType::Int64), None);
+    /// assert_eq!(InnerType::from("true").to_dtype_exact(DType::Float), None);
+    /// ```
+    pub fn to_dtype_exact(&self, dtype: DType) -> Option<InnerType> {
+        if let InnerType::Str(ref s) = *self {
+            return match dtype {
+                DType::Str | DType::Datetime => self.to_dtype(dtype),
+                _ => {
+                    match s.parse::<InnerType>() {
+                        Ok(InnerType::Str(_)) | Err(_) => None,
+                        Ok(x) => x.to_dtype_exact(dtype),
+                    }
+                }
+            };
+        }
+        let source = self.dtype()?;
+        if source != dtype && (source == DType::Bool || dtype == DType::Bool) &&
+           dtype != DType::Str {
+            return None;
+        }
+        let res = self.to_dtype(dtype)?;
+        if dtype == DType::Str || res.to_dtype(source).as_ref() == Some(self) {
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for DType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            DType::Float => "float",
+            DType::Int64 => "int64",
+            DType::Int32 => "int32",
+            DType::Float32 => "float32",
+            DType::UInt64 => "uint64",
+            DType::Bool => "bool",
+            DType::Str => "str",
+            DType::Datetime => "datetime",
+        };
+        write!(f, "{}", name)
+    }
+}
+
 impl AsMut<InnerType> for InnerType {
     fn as_mut(&mut self) -> &mut InnerType {
         &mut (*self)
     }
 }
 
+/// The value of a numeric entry, as a float.
+fn number(x: &InnerType) -> Option<f64> {
+    match *x {
+        InnerType::Float(x) => Some(x),
+        InnerType::Int64(x) => Some(x as f64),
+        InnerType::Int32(x) => Some(x as f64),
+        InnerType::Float32(x) => Some(x as f64),
+        InnerType::UInt64(x) => Some(x as f64),
+        _ => None,
+    }
+}
+
+/// The values of two numeric entries of different types, as floats. Arithmetic and comparisons
+/// between such entries are carried out on these, so that mixing integers and floats gives a
+/// `Float` instead of an empty entry.
+fn promote(x: &InnerType, y: &InnerType) -> Option<(f64, f64)> {
+    if mem::discriminant(x) == mem::discriminant(y) {
+        return None;
+    }
+    match (number(x), number(y)) {
+        (Some(x), Some(y)) => Some((x, y)),
+        _ => None,
+    }
+}
+
 impl Mul for InnerType {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self {
+        if let Some((x, y)) = promote(&self, &rhs) {
+            return InnerType::Float(x * y);
+        }
         match self {
             InnerType::Float(x) => {
                 match rhs {
@@ -55,7 +329,21 @@ impl Mul for InnerType {
                     _ => InnerType::Empty,
                 }
             }
-            InnerType::Str(_) => {
+            InnerType::Float32(x) => {
+                match rhs {
+                    InnerType::Float32(y) => InnerType::Float32(x * y),
+                    _ => InnerType::Empty,
+                }
+            }
+            InnerType::UInt64(x) => {
+                match rhs {
+                    InnerType::UInt64(y) => {
+                        x.checked_mul(y).map_or(InnerType::Empty, InnerType::UInt64)
+                    }
+                    _ => InnerType::Empty,
+                }
+            }
+            InnerType::Str(_) | InnerType::Datetime(_) | InnerType::Bool(_) => {
                 match rhs {
                     _ => InnerType::Empty,
                 }
@@ -65,6 +353,8 @@ impl Mul for InnerType {
                     InnerType::Float(y) => InnerType::Float(y),
                     InnerType::Int32(y) => InnerType::Int32(y),
                     InnerType::Int64(y) => InnerType::Int64(y),
+                    InnerType::Float32(y) => InnerType::Float32(y),
+                    InnerType::UInt64(y) => InnerType::UInt64(y),
                     _ => InnerType::Empty,
                 }
             }
@@ -84,6 +374,9 @@ impl Ord for InnerType {
 
 impl PartialOrd for InnerType {
     fn partial_cmp(&self, rhs: &Self) -> Option<Ordering> {
+        if let Some((x, y)) = promote(self, rhs) {
+            return Some(x.partial_cmp(&y).unwrap_or(Ordering::Equal));
+        }
         match self {
             &InnerType::Float(x) => {
                 match rhs {
@@ -106,17 +399,49 @@ impl PartialOrd for InnerType {
                     _ => panic!(),
                 }
             }
+            &InnerType::Float32(x) => {
+                match *rhs {
+                    InnerType::Float32(y) => x.partial_cmp(&y),
+                    InnerType::Empty => Some(Ordering::Greater),
+                    _ => panic!(),
+                }
+            }
+            &InnerType::UInt64(x) => {
+                match *rhs {
+                    InnerType::UInt64(y) => Some(x.cmp(&y)),
+                    InnerType::Empty => Some(Ordering::Greater),
+                    _ => panic!(),
+                }
+            }
+            &InnerType::Bool(x) => {
+                match *rhs {
+                    InnerType::Bool(y) => Some(x.cmp(&y)),
+                    InnerType::Empty => Some(Ordering::Greater),
+                    _ => panic!(),
+                }
+            }
             &InnerType::Str(ref x) => {
                 match rhs {
                     &InnerType::Str(ref y) => Some(x.cmp(&y)),
                     _ => panic!(),
                 }
             }
+            &InnerType::Datetime(x) => {
+                match *rhs {
+                    InnerType::Datetime(y) => Some(x.cmp(&y)),
+                    InnerType::Empty => Some(Ordering::Greater),
+                    _ => panic!(),
+                }
+            }
             &InnerType::Empty => {
                 match rhs {
                     &InnerType::Float(y) => Some((y as i32).cmp(&(y as i32 - 1))),
                     &InnerType::Int64(y) => Some(y.cmp(&(y - 1))),
                     &InnerType::Int32(y) => Some(y.cmp(&(y - 1))),
+                    &InnerType::Float32(_) |
+                    &InnerType::UInt64(_) |
+                    &InnerType::Bool(_) |
+                    &InnerType::Datetime(_) => Some(Ordering::Greater),
                     _ => panic!(),
                 }
             }
@@ -146,12 +471,36 @@ impl PartialEq for InnerType {
                     _ => false,
                 }
             }
+            &InnerType::Float32(x) => {
+                match rhs {
+                    &InnerType::Float32(y) => x == y,
+                    _ => false,
+                }
+            }
+            &InnerType::UInt64(x) => {
+                match rhs {
+                    &InnerType::UInt64(y) => x == y,
+                    _ => false,
+                }
+            }
+            &InnerType::Bool(x) => {
+                match rhs {
+                    &InnerType::Bool(y) => x == y,
+                    _ => false,
+                }
+            }
             &InnerType::Str(ref x) => {
                 match rhs {
                     &InnerType::Str(ref y) => x.to_owned() == y.to_owned(),
                     _ => false,
                 }
             }
+            &InnerType::Datetime(x) => {
+                match rhs {
+                    &InnerType::Datetime(y) => x == y,
+                    _ => false,
+                }
+            }
             &InnerType::Empty => {
                 match rhs {
                     &InnerType::Empty => true,
@@ -166,6 +515,9 @@ impl PartialEq for InnerType {
 impl Div for InnerType {
     type Output = Self;
     fn div(self, rhs: Self) -> Self {
+        if let Some((x, y)) = promote(&self, &rhs) {
+            return InnerType::Float(x / y);
+        }
         match self {
             InnerType::Float(x) => {
                 match rhs {
@@ -185,7 +537,21 @@ impl Div for InnerType {
                     _ => InnerType::Empty,
                 }
             }
-            InnerType::Str(_) => {
+            InnerType::Float32(x) => {
+                match rhs {
+                    InnerType::Float32(y) => InnerType::Float32(x / y),
+                    _ => InnerType::Empty,
+                }
+            }
+            InnerType::UInt64(x) => {
+                match rhs {
+                    InnerType::UInt64(y) => {
+                        x.checked_div(y).map_or(InnerType::Empty, InnerType::UInt64)
+                    }
+                    _ => InnerType::Empty,
+                }
+            }
+            InnerType::Str(_) | InnerType::Datetime(_) | InnerType::Bool(_) => {
                 match rhs {
                     _ => InnerType::Empty,
                 }
@@ -195,6 +561,8 @@ impl Div for InnerType {
                     InnerType::Float(y) => InnerType::Float(y),
                     InnerType::Int32(y) => InnerType::Int32(y),
                     InnerType::Int64(y) => InnerType::Int64(y),
+                    InnerType::Float32(y) => InnerType::Float32(y),
+                    InnerType::UInt64(y) => InnerType::UInt64(y),
                     _ => InnerType::Empty,
                 }
             }
@@ -206,6 +574,9 @@ impl Div for InnerType {
 impl Add for InnerType {
     type Output = Self;
     fn add(self, rhs: Self) -> Self {
+        if let Some((x, y)) = promote(&self, &rhs) {
+            return InnerType::Float(x + y);
+        }
         match self {
             InnerType::Float(x) => {
                 match rhs {
@@ -225,7 +596,21 @@ impl Add for InnerType {
                     _ => InnerType::Empty,
                 }
             }
-            InnerType::Str(_) => {
+            InnerType::Float32(x) => {
+                match rhs {
+                    InnerType::Float32(y) => InnerType::Float32(x + y),
+                    _ => InnerType::Empty,
+                }
+            }
+            InnerType::UInt64(x) => {
+                match rhs {
+                    InnerType::UInt64(y) => {
+                        x.checked_add(y).map_or(InnerType::Empty, InnerType::UInt64)
+                    }
+                    _ => InnerType::Empty,
+                }
+            }
+            InnerType::Str(_) | InnerType::Datetime(_) | InnerType::Bool(_) => {
                 match rhs {
                     _ => InnerType::Empty,
                 }
@@ -235,6 +620,8 @@ impl Add for InnerType {
                     InnerType::Float(y) => InnerType::Float(y),
                     InnerType::Int32(y) => InnerType::Int32(y),
                     InnerType::Int64(y) => InnerType::Int64(y),
+                    InnerType::Float32(y) => InnerType::Float32(y),
+                    InnerType::UInt64(y) => InnerType::UInt64(y),
                     _ => InnerType::Empty,
                 }
             }
@@ -245,6 +632,9 @@ impl Add for InnerType {
 impl Sub for InnerType {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self {
+        if let Some((x, y)) = promote(&self, &rhs) {
+            return InnerType::Float(x - y);
+        }
         match self {
             InnerType::Float(x) => {
                 match rhs {
@@ -264,8 +654,28 @@ impl Sub for InnerType {
                     _ => InnerType::Empty,
                 }
             }
-            InnerType::Str(_) => {
+            InnerType::Float32(x) => {
                 match rhs {
+                    InnerType::Float32(y) => InnerType::Float32(x - y),
+                    _ => InnerType::Empty,
+                }
+            }
+            InnerType::UInt64(x) => {
+                match rhs {
+                    InnerType::UInt64(y) => {
+                        x.checked_sub(y).map_or(InnerType::Empty, InnerType::UInt64)
+                    }
+                    _ => InnerType::Empty,
+                }
+            }
+            InnerType::Str(_) | InnerType::Bool(_) => {
+                match rhs {
+                    _ => InnerType::Empty,
+                }
+            }
+            InnerType::Datetime(x) => {
+                match rhs {
+                    InnerType::Datetime(y) => InnerType::Int64(x.timestamp() - y.timestamp()),
                     _ => InnerType::Empty,
                 }
             }
@@ -274,6 +684,8 @@ impl Sub for InnerType {
                     InnerType::Float(y) => InnerType::Float(y),
                     InnerType::Int32(y) => InnerType::Int32(y),
                     InnerType::Int64(y) => InnerType::Int64(y),
+                    InnerType::Float32(y) => InnerType::Float32(y),
+                    InnerType::UInt64(y) => InnerType::UInt64(y),
                     _ => InnerType::Empty,
                 }
             }
@@ -295,13 +707,71 @@ impl Zero for InnerType {
     }
 }
 
+impl FromPrimitive for InnerType {
+    fn from_i64(n: i64) -> Option<InnerType> {
+        Some(InnerType::Int64(n))
+    }
+    fn from_u64(n: u64) -> Option<InnerType> {
+        if n <= i64::MAX as u64 {
+            Some(InnerType::Int64(n as i64))
+        } else {
+            Some(InnerType::UInt64(n))
+        }
+    }
+    fn from_f32(n: f32) -> Option<InnerType> {
+        Some(InnerType::Float32(n))
+    }
+    fn from_f64(n: f64) -> Option<InnerType> {
+        Some(InnerType::Float(n))
+    }
+}
+
+impl ToPrimitive for InnerType {
+    fn to_i64(&self) -> Option<i64> {
+        match *self {
+            InnerType::Float(x) => x.to_i64(),
+            InnerType::Int64(x) => Some(x),
+            InnerType::Int32(x) => Some(x as i64),
+            InnerType::Float32(x) => x.to_i64(),
+            InnerType::UInt64(x) => x.to_i64(),
+            InnerType::Bool(x) => Some(x as i64),
+            InnerType::Datetime(x) => Some(x.timestamp()),
+            _ => None,
+        }
+    }
+    fn to_u64(&self) -> Option<u64> {
+        match *self {
+            InnerType::Float(x) => x.to_u64(),
+            InnerType::Int64(x) => x.to_u64(),
+            InnerType::Int32(x) => x.to_u64(),
+            InnerType::Float32(x) => x.to_u64(),
+            InnerType::UInt64(x) => Some(x),
+            InnerType::Bool(x) => Some(x as u64),
+            _ => None,
+        }
+    }
+    fn to_f64(&self) -> Option<f64> {
+        match *self {
+            InnerType::Float(x) => Some(x),
+            InnerType::Int64(x) => Some(x as f64),
+            InnerType::Int32(x) => Some(x as f64),
+            InnerType::Float32(x) => Some(x as f64),
+            InnerType::UInt64(x) => Some(x as f64),
+            InnerType::Bool(x) => Some(x as i64 as f64),
+            InnerType::Datetime(x) => Some(x.timestamp() as f64),
+            _ => None,
+        }
+    }
+}
+
 impl Empty<InnerType> for InnerType {
     fn empty() -> InnerType {
         InnerType::Empty
     }
     fn is_empty(&self) -> bool {
         match *self {
-            InnerType::Float(x) => x == NAN,
+            InnerType::Float(x) => x.is_nan(),
+            InnerType::Float32(x) => x.is_nan(),
             InnerType::Empty => true,
             _ => false,
         }
@@ -310,6 +780,69 @@ impl Empty<InnerType> for InnerType {
 
 
 
+impl ToDType for InnerType {
+    fn element_dtype() -> Option<DType> {
+        None
+    }
+    fn dtype(&self) -> Option<DType> {
+        InnerType::dtype(self)
+    }
+}
+
+impl ToHashKey for InnerType {
+    fn hash_key(&self) -> HashKey {
+        match *self {
+            InnerType::Float(x) => x.hash_key(),
+            InnerType::Int64(x) => HashKey::Int(x),
+            InnerType::Int32(x) => HashKey::Int(x as i64),
+            InnerType::Float32(x) => (x as f64).hash_key(),
+            InnerType::UInt64(x) if x > i64::MAX as u64 => HashKey::UInt(x),
+            InnerType::UInt64(x) => HashKey::Int(x as i64),
+            InnerType::Bool(x) => HashKey::Bool(x),
+            InnerType::Str(ref x) => HashKey::Str(x.clone()),
+            InnerType::Datetime(x) => HashKey::Datetime(x),
+            InnerType::Empty => HashKey::Empty,
+        }
+    }
+}
+
+impl HashKey {
+    /// The label that an entry with this key goes by, or `None` for empty entries.
+    pub fn to_label(&self) -> Option<OuterType> {
+        match *self {
+            HashKey::Float(x) => Some(OuterType::Float(FloatLabel(f64::from_bits(x)))),
+            HashKey::Int(x) => Some(OuterType::Int64(x)),
+            HashKey::UInt(x) => Some(OuterType::UInt64(x)),
+            HashKey::Bool(x) => Some(OuterType::Bool(x)),
+            HashKey::Str(ref x) => Some(OuterType::Str(x.clone())),
+            HashKey::Datetime(x) => Some(OuterType::Datetime(x)),
+            HashKey::Empty => None,
+        }
+    }
+}
+
+impl fmt::Display for OuterType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            OuterType::Str(ref x) => write!(f, "{}", x),
+            OuterType::Int64(x) => write!(f, "{}", x),
+            OuterType::Int32(x) => write!(f, "{}", x),
+            OuterType::USize(x) => write!(f, "{}", x),
+            OuterType::Datetime(x) => write!(f, "{}", x),
+            OuterType::Float(x) => write!(f, "{}", x.0),
+            OuterType::UInt64(x) => write!(f, "{}", x),
+            OuterType::Bool(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+impl From<OuterType> for String {
+    fn from(o: OuterType) -> String {
+        o.to_string()
+    }
+}
+
+
 impl Default for OuterType {
     fn default() -> OuterType {
         OuterType::Int32(1)
@@ -343,6 +876,24 @@ impl From<i32> for InnerType {
     }
 }
 
+impl From<f32> for InnerType {
+    fn from(f: f32) -> InnerType {
+        InnerType::Float32(f)
+    }
+}
+
+impl From<u64> for InnerType {
+    fn from(i: u64) -> InnerType {
+        InnerType::UInt64(i)
+    }
+}
+
+impl From<bool> for InnerType {
+    fn from(b: bool) -> InnerType {
+        InnerType::Bool(b)
+    }
+}
+
 impl<'a> From<&'a i64> for InnerType {
     fn from(i: &'a i64) -> InnerType {
         InnerType::Int64(*i)
@@ -405,11 +956,33 @@ impl<'a> From<&'a String> for OuterType {
     }
 }
 
-// impl<'a> From<DateTime<UTC>> for OuterType {
-//     fn from(d: DateTime<UTC>) -> OuterType {
-//         OuterType::Date(d.to_owned())
-//     }
-// }
+impl From<Datetime> for OuterType {
+    fn from(d: Datetime) -> OuterType {
+        OuterType::Datetime(d)
+    }
+}
+
+impl From<Datetime> for InnerType {
+    fn from(d: Datetime) -> InnerType {
+        InnerType::Datetime(d)
+    }
+}
+
+/// An entry holding a label, keeping its type. `USize` labels become `Int64` entries.
+impl From<OuterType> for InnerType {
+    fn from(o: OuterType) -> InnerType {
+        match o {
+            OuterType::Str(s) => InnerType::Str(s),
+            OuterType::Int64(i) => InnerType::Int64(i),
+            OuterType::Int32(i) => InnerType::Int32(i),
+            OuterType::USize(i) => InnerType::Int64(i as i64),
+            OuterType::Datetime(d) => InnerType::Datetime(d),
+            OuterType::Float(x) => InnerType::Float(x.0),
+            OuterType::UInt64(i) => InnerType::UInt64(i),
+            OuterType::Bool(b) => InnerType::Bool(b),
+        }
+    }
+}
 
 impl From<i64> for OuterType {
     fn from(i: i64) -> OuterType {
@@ -437,6 +1010,24 @@ impl<'a> From<&'a i32> for OuterType {
     }
 }
 
+impl From<f64> for OuterType {
+    fn from(x: f64) -> OuterType {
+        OuterType::Float(FloatLabel(x))
+    }
+}
+
+impl From<u64> for OuterType {
+    fn from(x: u64) -> OuterType {
+        OuterType::UInt64(x)
+    }
+}
+
+impl From<bool> for OuterType {
+    fn from(x: bool) -> OuterType {
+        OuterType::Bool(x)
+    }
+}
+
 impl<'a> From<usize> for OuterType {
     fn from(i: usize) -> OuterType {
         OuterType::USize(i)
@@ -446,14 +1037,20 @@ impl<'a> From<usize> for OuterType {
 impl FromStr for InnerType {
     type Err = ErrorKind;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Ok(x) = s.parse::<f64>() {
-            return Ok(InnerType::Float(x));
+        if let Ok(x) = s.parse::<bool>() {
+            return Ok(InnerType::Bool(x));
         }
         if let Ok(x) = s.parse::<i64>() {
             return Ok(InnerType::Int64(x));
         }
-        if let Ok(x) = s.parse::<i32>() {
-            return Ok(InnerType::Int32(x));
+        if let Ok(x) = s.parse::<u64>() {
+            return Ok(InnerType::UInt64(x));
+        }
+        if let Ok(x) = s.parse::<f64>() {
+            return Ok(InnerType::Float(x));
+        }
+        if let Ok(x) = s.parse::<Datetime>() {
+            return Ok(InnerType::Datetime(x));
         }
         if let Ok(x) = s.parse::<String>() {
             return Ok(InnerType::Str(x));
@@ -0,0 +1,277 @@
+//! Utah hierarchical index
+
+use util::error::*;
+use util::types::*;
+use util::traits::*;
+use dataframe::DataFrame;
+use mixedtypes::{InnerType, OuterType};
+use ndarray::Array;
+use std::collections::HashMap;
+
+/// A hierarchical axis of a dataframe. Every entry is a tuple of labels, one for each named
+/// level, from the outermost level to the innermost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiIndex {
+    pub names: Vec<String>,
+    pub keys: Vec<Vec<OuterType>>,
+}
+
+/// A read-only dataframe whose index and columns are both hierarchical.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultiIndexFrame<T>
+    where T: UtahNum
+{
+    pub columns: MultiIndex,
+    pub data: Matrix<T>,
+    pub index: MultiIndex,
+}
+
+/// The outer keys, inner labels and per-entry positions of a split multi-index.
+type Split = (Vec<Vec<OuterType>>, Vec<OuterType>, Vec<(usize, usize)>);
+
+impl MultiIndex {
+    /// Create a multi-index from level names and one key per entry. Every key must have one
+    /// label per level.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let keys = vec![vec![OuterType::Int32(2016), OuterType::from("NY")],
+    ///                 vec![OuterType::Int32(2016), OuterType::from("CA")]];
+    /// let index = MultiIndex::new(&["year", "state"], keys).unwrap();
+    /// assert_eq!(index.locate(&[OuterType::Int32(2016)]), vec![0, 1]);
+    /// ```
+    pub fn new<U: Clone>(names: &[U], keys: Vec<Vec<OuterType>>) -> Result<MultiIndex>
+        where String: From<U>
+    {
+        for key in &keys {
+            if key.len() != names.len() {
+                return Err(ErrorKind::IndexShapeMismatch(names.len().to_string(),
+                                                         key.len().to_string())
+                    .into());
+            }
+        }
+        Ok(MultiIndex {
+            names: names.iter().map(|x| x.clone().into()).collect(),
+            keys,
+        })
+    }
+
+    /// Create a single-level multi-index from plain labels.
+    pub fn from_labels(name: &str, labels: &[String]) -> MultiIndex {
+        MultiIndex {
+            names: vec![name.to_string()],
+            keys: labels.iter().map(|x| vec![OuterType::Str(x.clone())]).collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub fn nlevels(&self) -> usize {
+        self.names.len()
+    }
+
+    /// The position of the level called `name`.
+    pub fn level(&self, name: &str) -> Result<usize> {
+        match self.names.iter().position(|x| x == name) {
+            Some(i) => Ok(i),
+            None => Err(ErrorKind::InvalidLevelName(name.to_string()).into()),
+        }
+    }
+
+    /// The labels of a single level, one per entry.
+    pub fn level_values(&self, level: usize) -> Vec<OuterType> {
+        self.keys.iter().map(|x| x[level].clone()).collect()
+    }
+
+    /// The positions of the entries whose keys start with `partial`.
+    pub fn locate(&self, partial: &[OuterType]) -> Vec<usize> {
+        self.keys
+            .iter()
+            .enumerate()
+            .filter(|&(_, key)| key.len() >= partial.len() && key[..partial.len()] == *partial)
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Flatten every key into a single label, joining its levels with `sep`.
+    pub fn flatten(&self, sep: &str) -> Vec<String> {
+        self.keys
+            .iter()
+            .map(|key| key.iter().map(|x| x.to_string()).collect::<Vec<String>>().join(sep))
+            .collect()
+    }
+
+    /// Split off the innermost level, returning the remaining outer keys (distinct, in order of
+    /// first occurrence), the distinct inner labels, and for every entry the positions of its
+    /// outer key and inner label.
+    fn split_innermost(&self) -> Split {
+        let (mut outer, mut inner) = (Vec::new(), Vec::new());
+        let (mut outer_pos, mut inner_pos) = (HashMap::new(), HashMap::new());
+        let mut positions = Vec::with_capacity(self.len());
+        for key in &self.keys {
+            let (head, last) = key.split_at(key.len() - 1);
+            let o = *outer_pos.entry(head.to_vec()).or_insert_with(|| {
+                outer.push(head.to_vec());
+                outer.len() - 1
+            });
+            let i = *inner_pos.entry(last[0].clone()).or_insert_with(|| {
+                inner.push(last[0].clone());
+                inner.len() - 1
+            });
+            positions.push((o, i));
+        }
+        (outer, inner, positions)
+    }
+}
+
+impl<T> MultiIndexFrame<T>
+    where T: UtahNum
+{
+    /// Create a hierarchical dataframe, checking that the index and columns fit the data.
+    pub fn new(data: Matrix<T>, index: MultiIndex, columns: MultiIndex) -> Result<Self> {
+        let (nrows, ncols) = data.dim();
+        if index.len() != nrows {
+            return Err(ErrorKind::IndexShapeMismatch(nrows.to_string(), index.len().to_string())
+                .into());
+        }
+        if columns.len() != ncols {
+            return Err(ErrorKind::ColumnShapeMismatch(ncols.to_string(),
+                                                      columns.len().to_string())
+                .into());
+        }
+        Ok(MultiIndexFrame {
+            columns,
+            data,
+            index,
+        })
+    }
+
+    /// Select the rows whose index keys start with `partial`. The levels matched by `partial`
+    /// are dropped from the result.
+    pub fn loc(&self, partial: &[OuterType]) -> Result<MultiIndexFrame<T>> {
+        if partial.len() > self.index.nlevels() {
+            return Err(ErrorKind::IndexShapeMismatch(self.index.nlevels().to_string(),
+                                                     partial.len().to_string())
+                .into());
+        }
+        let rows = self.index.locate(partial);
+        let mut c = Vec::with_capacity(rows.len() * self.columns.len());
+        for &r in &rows {
+            c.extend(self.data.row(r).iter().cloned());
+        }
+        let index = MultiIndex {
+            names: self.index.names[partial.len()..].to_vec(),
+            keys: rows.iter().map(|&r| self.index.keys[r][partial.len()..].to_vec()).collect(),
+        };
+        let d = Array::from_shape_vec((rows.len(), self.columns.len()), c).unwrap();
+        MultiIndexFrame::new(d, index, self.columns.clone())
+    }
+
+    /// Move the innermost level of the columns into the index, as its innermost level. Entries
+    /// for combinations of labels that did not occur are left empty.
+    pub fn stack(&self) -> Result<MultiIndexFrame<T>> {
+        Ok(self.transpose().unstack()?.transpose())
+    }
+
+    /// Move the innermost level of the index into the columns, as their innermost level.
+    /// Entries for combinations of labels that did not occur are left empty, and two entries
+    /// for the same combination are an error.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let a = arr2(&[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+    /// let df : DataFrame<f64> = DataFrame::new(a).columns(&["k", "v"]).unwrap();
+    /// let res = df.set_index(&["k"]).unwrap().unstack().unwrap();
+    /// assert_eq!(res.data.dim(), (1, 4));
+    /// ```
+    pub fn unstack(&self) -> Result<MultiIndexFrame<T>> {
+        if self.index.nlevels() == 0 {
+            return Err(ErrorKind::InvalidLevelName("no level to unstack".to_string()).into());
+        }
+        let (outer, inner, positions) = self.index.split_innermost();
+        let ncols = self.columns.len();
+        let mut d = Array::from_elem((outer.len(), ncols * inner.len()), T::empty());
+        let mut seen = vec![false; outer.len() * inner.len()];
+        for (r, &(o, i)) in positions.iter().enumerate() {
+            if seen[o * inner.len() + i] {
+                let outer = outer[o].iter().map(|x| x.to_string()).collect::<Vec<_>>().join("|");
+                return Err(ErrorKind::DuplicateEntry(outer, inner[i].to_string()).into());
+            }
+            seen[o * inner.len() + i] = true;
+            for c in 0..ncols {
+                d[[o, c * inner.len() + i]] = self.data[[r, c]].clone();
+            }
+        }
+
+        let name = self.index.names[self.index.nlevels() - 1].clone();
+        let mut columns = MultiIndex {
+            names: self.columns.names.clone(),
+            keys: Vec::with_capacity(ncols * inner.len()),
+        };
+        columns.names.push(name);
+        for key in &self.columns.keys {
+            for label in &inner {
+                let mut key = key.clone();
+                key.push(label.clone());
+                columns.keys.push(key);
+            }
+        }
+        let index = MultiIndex {
+            names: self.index.names[..self.index.nlevels() - 1].to_vec(),
+            keys: outer,
+        };
+        MultiIndexFrame::new(d, index, columns)
+    }
+
+    /// Swap the index and columns, transposing the data.
+    pub fn transpose(&self) -> MultiIndexFrame<T> {
+        MultiIndexFrame {
+            columns: self.index.clone(),
+            data: self.data.t().to_owned(),
+            index: self.columns.clone(),
+        }
+    }
+
+    /// Move the levels of the index into leading data columns named after them, leaving a
+    /// plain dataframe with a default index. Column keys are joined with `|`.
+    pub fn reset_index(&self) -> Result<DataFrame<InnerType>>
+        where InnerType: From<T>
+    {
+        let nlevels = self.index.nlevels();
+        let mut c = Vec::with_capacity(self.index.len() * (nlevels + self.columns.len()));
+        for (key, row) in self.index.keys.iter().zip(self.data.outer_iter()) {
+            c.extend(key.iter().map(|x| <InnerType as From<OuterType>>::from(x.clone())));
+            c.extend(row.iter().map(|x| InnerType::from(x.clone())));
+        }
+        let mut names = self.index.names.clone();
+        names.extend(self.columns.flatten("|"));
+        let d = Array::from_shape_vec((self.index.len(), names.len()), c).unwrap();
+        DataFrame::new(d).columns(&names[..])
+    }
+
+    /// Flatten into a plain dataframe, joining the levels of every key with `sep`.
+    pub fn to_df(&self, sep: &str) -> Result<DataFrame<T>> {
+        DataFrame::new(self.data.clone())
+            .columns(&self.columns.flatten(sep)[..])?
+            .index(&self.index.flatten(sep)[..])
+    }
+}
+
+impl<T> From<DataFrame<T>> for MultiIndexFrame<T>
+    where T: UtahNum
+{
+    /// View a plain dataframe as a hierarchical one with a single level on either axis.
+    fn from(df: DataFrame<T>) -> MultiIndexFrame<T> {
+        MultiIndexFrame {
+            columns: MultiIndex::from_labels("columns", &df.columns[..]),
+            index: MultiIndex::from_labels("index", &df.index[..]),
+            data: df.data,
+        }
+    }
+}
@@ -15,6 +15,12 @@ pub use util::traits::*;
 pub use util::types::*;
 pub use ndarray::{arr2, arr1, ArrayView1, ArrayView2, Axis, stack};
 pub use mixedtypes::*;
+pub use datetime::*;
+pub use categorical::*;
+pub use columnar::ColumnFrame;
+pub use schema::*;
+pub use multiindex::*;
 pub use util::macros::*;
 pub use util::error::*;
 pub use util::readcsv::*;
+pub use implement::impl_frequency::crosstab;
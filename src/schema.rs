@@ -0,0 +1,66 @@
+//! Utah schemas
+//!
+//! A schema names the columns that a dataframe should have, the type of their entries and
+//! whether they may hold empty entries. It can be taken from a dataframe with
+//! `ToSchema::schema`, checked with `ToSchema::validate`, and enforced while reading a csv
+//! file with `ReadCSVSchema::read_csv_with_schema`.
+
+use mixedtypes::DType;
+
+/// A column of a schema. A field without a type accepts entries of any type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Field {
+    pub name: String,
+    pub dtype: Option<DType>,
+    pub nullable: bool,
+}
+
+/// The columns that a dataframe should have, in order.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Schema {
+    pub fields: Vec<Field>,
+}
+
+impl Schema {
+    pub fn new() -> Schema {
+        Schema { fields: Vec::new() }
+    }
+
+    /// Add a column of entries of type `dtype`.
+    ///
+    /// ```
+    /// use utah::prelude::*;
+    /// let schema = Schema::new()
+    ///     .field("name", DType::Str, false)
+    ///     .field("age", DType::Int64, true);
+    /// assert_eq!(schema.names(), vec!["name", "age"]);
+    /// assert!(schema.get("age").unwrap().nullable);
+    /// ```
+    pub fn field(mut self, name: &str, dtype: DType, nullable: bool) -> Schema {
+        self.fields.push(Field {
+            name: name.to_string(),
+            dtype: Some(dtype),
+            nullable,
+        });
+        self
+    }
+
+    /// Add a column of entries of any type.
+    pub fn any_field(mut self, name: &str, nullable: bool) -> Schema {
+        self.fields.push(Field {
+            name: name.to_string(),
+            dtype: None,
+            nullable,
+        });
+        self
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        self.fields.iter().map(|x| &x.name[..]).collect()
+    }
+
+    /// The field of the column `name`.
+    pub fn get(&self, name: &str) -> Option<&Field> {
+        self.fields.iter().find(|x| x.name == name)
+    }
+}
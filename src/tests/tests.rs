@@ -11,7 +11,9 @@ use std::rc::Rc;
 use rand::{thread_rng, Rng};
 use std::collections::{HashMap, BTreeMap};
 use std::f64::NAN;
+use num::traits::ToPrimitive;
 use prelude::*;
+use columnar::Column;
 
 #[test]
 fn outer_left_join() {
@@ -283,3 +285,1052 @@ fn dataframe_macro() {
 //     }
 //
 // }
+
+#[test]
+fn dataframe_count_prod() {
+    let a = arr2(&[[2., NAN], [3., 4.], [5., 2.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    let z: DataFrame<f64> = df.countdf(UtahAxis::Column).as_df().unwrap();
+    let expected = DataFrame::new(arr2(&[[3., 2.]])).columns(&["a", "b"]).unwrap();
+    assert_eq!(z, expected);
+
+    let z: DataFrame<f64> = df.prod(UtahAxis::Column).as_df().unwrap();
+    let expected = DataFrame::new(arr2(&[[30., 8.]])).columns(&["a", "b"]).unwrap();
+    assert_eq!(z, expected);
+}
+
+#[test]
+fn dataframe_median_quantile() {
+    let a = arr2(&[[1., 4.], [3., NAN], [2., 2.], [4., 3.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    let z: DataFrame<f64> = df.median(UtahAxis::Column).as_df().unwrap();
+    let expected = DataFrame::new(arr2(&[[2.5, 3.]])).columns(&["a", "b"]).unwrap();
+    assert_eq!(z, expected);
+
+    let z = df.quantile(0.25, Interpolation::Linear, UtahAxis::Column).unwrap().as_array().unwrap();
+    assert_eq!(z, arr1(&[1.75, 2.5]));
+    let z = df.quantile(0.25, Interpolation::Lower, UtahAxis::Column).unwrap().as_array().unwrap();
+    assert_eq!(z, arr1(&[1., 2.]));
+    let z = df.quantile(0.25, Interpolation::Higher, UtahAxis::Column).unwrap().as_array().unwrap();
+    assert_eq!(z, arr1(&[2., 3.]));
+    let z = df.quantile(0.25, Interpolation::Midpoint, UtahAxis::Column).unwrap().as_array().unwrap();
+    assert_eq!(z, arr1(&[1.5, 2.5]));
+    let z = df.quantile(0.25, Interpolation::Nearest, UtahAxis::Column).unwrap().as_array().unwrap();
+    assert_eq!(z, arr1(&[2., 2.]));
+    assert!(df.quantile(1.5, Interpolation::Linear, UtahAxis::Column).is_err());
+    let ints: DataFrame<i64> = DataFrame::new(arr2(&[[10], [50]]));
+    let z = ints.quantile(0.75, Interpolation::Linear, UtahAxis::Column).unwrap();
+    assert_eq!(z.as_array().unwrap(), arr1(&[40]));
+
+    let z: DataFrame<f64> = df.df_iter(UtahAxis::Column).median().as_df().unwrap();
+    let expected = DataFrame::new(arr2(&[[2.5, 3.]])).columns(&["a", "b"]).unwrap();
+    assert_eq!(z, expected);
+    let z: DataFrame<f64> = df.df_iter(UtahAxis::Row).median().as_df().unwrap();
+    let expected = DataFrame::new(arr2(&[[2.5], [3.], [2.], [3.5]])).columns(&["0"]).unwrap();
+    assert_eq!(z, expected);
+}
+
+#[test]
+fn dataframe_var_mode_nunique() {
+    let a = arr2(&[[2., 1.], [4., 1.], [4., 4.], [6., NAN]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    let z = df.var(UtahAxis::Column).as_array().unwrap();
+    assert_eq!(z, arr1(&[8. / 3., 3.]));
+
+    let z = df.mode(UtahAxis::Column).as_array().unwrap();
+    assert_eq!(z, arr1(&[4., 1.]));
+
+    let z = df.df_iter(UtahAxis::Column).nunique().as_array().unwrap();
+    assert_eq!(z, arr1(&[3., 2.]));
+}
+
+#[test]
+fn dataframe_describe() {
+    let a = arr2(&[[1., 4.], [3., NAN], [2., 2.], [4., 3.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    let z = df.describe().unwrap();
+    let b = arr2(&[[4., 3.],
+                   [2.5, 3.],
+                   [(5.0f64 / 3.).sqrt(), 1.],
+                   [1., 2.],
+                   [1.75, 2.5],
+                   [2.5, 3.],
+                   [3.25, 3.5],
+                   [4., 4.]]);
+    let expected = DataFrame::new(b)
+        .columns(&["a", "b"])
+        .unwrap()
+        .index(&["count", "mean", "std", "min", "25%", "50%", "75%", "max"])
+        .unwrap();
+    assert_eq!(z, expected);
+}
+
+#[test]
+fn dataframe_describe_mixed() {
+    let a = arr2(&[[InnerType::Str("x".into()), InnerType::Int32(1)],
+                   [InnerType::Str("y".into()), InnerType::Int32(3)],
+                   [InnerType::Str("x".into()), InnerType::Empty]]);
+    let df: DataFrame<InnerType> = DataFrame::new(a).columns(&["name", "value"]).unwrap();
+    let z = df.describe().unwrap();
+    assert_eq!(z.index,
+               vec!["count", "unique", "top", "freq", "mean", "std", "min", "25%", "50%",
+                    "75%", "max"]);
+    assert_eq!(z.data[[0, 0]], InnerType::Float(3.));
+    assert_eq!(z.data[[1, 0]], InnerType::Float(2.));
+    assert_eq!(z.data[[2, 0]], InnerType::Str("x".into()));
+    assert_eq!(z.data[[3, 0]], InnerType::Float(2.));
+    assert_eq!(z.data[[4, 0]], InnerType::Empty);
+    assert_eq!(z.data[[0, 1]], InnerType::Float(2.));
+    assert_eq!(z.data[[1, 1]], InnerType::Empty);
+    assert_eq!(z.data[[4, 1]], InnerType::Float(2.));
+    assert_eq!(z.data[[10, 1]], InnerType::Float(3.));
+}
+
+#[test]
+fn dataframe_corr_cov() {
+    let a = arr2(&[[1., 2., 9.], [2., 4., NAN], [3., 8., 7.], [4., 16., 6.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b", "c"]).unwrap();
+
+    let z = df.cov().unwrap();
+    assert_eq!(z.columns, vec!["a", "b", "c"]);
+    assert_eq!(z.index, vec!["a", "b", "c"]);
+    assert_eq!(z.data[[0, 0]], 5. / 3.);
+    assert!((z.data[[0, 2]] + 7. / 3.).abs() < 1e-12);
+    assert_eq!(z.data[[0, 2]], z.data[[2, 0]]);
+
+    let z = df.corr(CorrelationMethod::Spearman).unwrap();
+    assert_eq!(z.data[[0, 1]], 1.);
+    assert_eq!(z.data[[1, 2]], -1.);
+
+    let z = df.corr(CorrelationMethod::Kendall).unwrap();
+    assert_eq!(z.data[[0, 1]], 1.);
+    assert_eq!(z.data[[0, 2]], -1.);
+
+    let z = df.corr(CorrelationMethod::Pearson).unwrap();
+    assert_eq!(z.data[[1, 1]], 1.);
+    assert!(z.data[[0, 1]] > 0.9 && z.data[[0, 1]] < 1.);
+}
+
+#[test]
+fn dataframe_idxmax_idxmin() {
+    let a = arr2(&[[2., NAN, 7.], [8., NAN, 7.], [1., NAN, 3.]]);
+    let df: DataFrame<f64> = DataFrame::new(a)
+        .columns(&["a", "b", "c"])
+        .unwrap()
+        .index(&["x", "y", "z"])
+        .unwrap();
+    let z: Vec<Option<String>> = df.idxmax(UtahAxis::Column).collect();
+    assert_eq!(z, vec![Some("y".to_string()), None, Some("x".to_string())]);
+
+    let z: Vec<Option<String>> = df.idxmin(UtahAxis::Row).collect();
+    assert_eq!(z,
+               vec![Some("a".to_string()), Some("c".to_string()), Some("a".to_string())]);
+
+    let z = df.df_iter(UtahAxis::Column).idxmin().as_df().unwrap();
+    let b = arr2(&[[InnerType::Str("z".into()), InnerType::Empty, InnerType::Str("z".into())]]);
+    let expected = DataFrame::new(b).columns(&["a", "b", "c"]).unwrap();
+    assert_eq!(z, expected);
+}
+
+#[test]
+fn dataframe_cumulative() {
+    {
+        let a = arr2(&[[2., 1.], [NAN, 3.], [4., 2.]]);
+        let mut df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+        let z = df.cumsum(UtahAxis::Column).as_df().unwrap();
+        let b = arr2(&[[2., 1.], [NAN, 4.], [6., 6.]]);
+        assert_eq!(z.data.column(1), b.column(1));
+        assert_eq!(z.data[[2, 0]], 6.);
+        assert!(df.data[[1, 0]].is_nan());
+        assert_eq!(df.data[[2, 0]], 6.);
+    }
+    {
+        let a = arr2(&[[2., 1., 3.], [1., 3., 2.]]);
+        let mut df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b", "c"]).unwrap();
+        let z = df.cumprod(UtahAxis::Row).as_df().unwrap();
+        let expected = DataFrame::new(arr2(&[[2., 2., 6.], [1., 3., 6.]]))
+            .columns(&["a", "b", "c"])
+            .unwrap();
+        assert_eq!(z, expected);
+    }
+    {
+        let a = arr2(&[[2, 1], [1, 3], [4, 2]]);
+        let mut df: DataFrame<i32> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+        let _ = df.df_iter_mut(UtahAxis::Column).cummax().as_df();
+        assert_eq!(df.data, arr2(&[[2, 1], [2, 3], [4, 3]]));
+        let _ = df.cummin(UtahAxis::Row).as_df();
+        assert_eq!(df.data, arr2(&[[2, 1], [2, 2], [4, 3]]));
+    }
+}
+
+#[test]
+fn dataframe_rolling() {
+    let a = arr2(&[[1., 4.], [2., NAN], [3., 6.], [4., 2.], [5., 8.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+
+    let z = df.rolling(3, 2, false).sum().unwrap();
+    assert_eq!(z.index, df.index);
+    assert!(z.data[[0, 0]].is_nan());
+    assert_eq!(z.data.column(0).slice(s![1..]), arr1(&[3., 6., 9., 12.]));
+    assert!(z.data[[1, 1]].is_nan());
+    assert_eq!(z.data.column(1).slice(s![2..]), arr1(&[10., 8., 16.]));
+
+    let z = df.rolling(3, 1, false).mean().unwrap();
+    assert_eq!(z.data.column(0), arr1(&[1., 1.5, 2., 3., 4.]));
+    assert_eq!(z.data.column(1), arr1(&[4., 4., 5., 4., 16. / 3.]));
+
+    let z = df.rolling(3, 1, false).max().unwrap();
+    assert_eq!(z.data.column(1), arr1(&[4., 4., 6., 6., 8.]));
+    let z = df.rolling(3, 1, false).min().unwrap();
+    assert_eq!(z.data.column(1), arr1(&[4., 4., 4., 2., 2.]));
+
+    let z = df.rolling(3, 3, true).median().unwrap();
+    assert!(z.data[[0, 0]].is_nan());
+    assert_eq!(z.data.column(0).slice(s![1..4]), arr1(&[2., 3., 4.]));
+    assert!(z.data[[4, 0]].is_nan());
+
+    let z = df.rolling(2, 2, false).std().unwrap();
+    assert!((z.data[[1, 0]] - 0.5f64.sqrt()).abs() < 1e-12);
+
+    let z = df.rolling(2, 1, false).apply(|xs| xs.iter().fold(1., |acc, x| acc * x)).unwrap();
+    assert_eq!(z.data.column(0), arr1(&[1., 2., 6., 12., 20.]));
+
+    assert!(df.rolling(0, 0, false).sum().is_err());
+    assert!(df.rolling(2, 3, false).sum().is_err());
+}
+
+#[test]
+fn dataframe_expanding_ewm() {
+    let a = arr2(&[[1., 4.], [2., NAN], [3., 2.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+
+    let z = df.expanding(2).sum().unwrap();
+    assert!(z.data[[0, 0]].is_nan());
+    assert_eq!(z.data.column(0).slice(s![1..]), arr1(&[3., 6.]));
+    assert!(z.data[[1, 1]].is_nan());
+    assert_eq!(z.data[[2, 1]], 6.);
+    let z = df.expanding(1).max().unwrap();
+    assert_eq!(z.data.column(1), arr1(&[4., 4., 4.]));
+    assert!(df.expanding(5).mean().unwrap().data.iter().all(|x| x.is_nan()));
+    let z = df.expanding(1).median().unwrap();
+    assert_eq!(z.data, arr2(&[[1., 4.], [1.5, 4.], [2., 3.]]));
+
+    let z = df.ewm(Decay::Alpha(0.5), false).mean().unwrap();
+    assert_eq!(z.data.column(0), arr1(&[1., 1.5, 2.25]));
+    assert_eq!(z.data.column(1).slice(s![..2]), arr1(&[4., 4.]));
+    assert!((z.data[[2, 1]] - 8. / 3.).abs() < 1e-12);
+    let z = df.ewm(Decay::Span(3.), true).mean().unwrap();
+    assert!((z.data[[2, 0]] - 4.25 / 1.75).abs() < 1e-12);
+
+    let z = df.ewm(Decay::HalfLife(1.), true).var().unwrap();
+    assert!(z.data[[0, 0]].is_nan());
+    assert!((z.data[[1, 0]] - 0.5).abs() < 1e-12);
+    let s = df.ewm(Decay::HalfLife(1.), true).std().unwrap();
+    assert!((s.data[[2, 0]] - z.data[[2, 0]].sqrt()).abs() < 1e-12);
+
+    assert!(df.ewm(Decay::Alpha(1.5), true).mean().is_err());
+    assert!(df.ewm(Decay::Span(0.5), true).mean().is_err());
+}
+
+#[test]
+fn dataframe_shift_diff_pct_change() {
+    let a = arr2(&[[1., 2., 4.], [2., NAN, 8.], [4., 4., 0.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b", "c"]).unwrap();
+
+    let z = df.shift(1, UtahAxis::Column).as_df().unwrap();
+    assert_eq!(z.index, df.index);
+    assert_eq!(z.columns, df.columns);
+    assert!(z.data.row(0).iter().all(|x| x.is_nan()));
+    assert_eq!(z.data.column(0).slice(s![1..]), arr1(&[1., 2.]));
+    assert!(z.data[[2, 1]].is_nan());
+
+    let z = df.shift(-1, UtahAxis::Row).as_df().unwrap();
+    assert_eq!(z.data.row(0).slice(s![..2]), arr1(&[2., 4.]));
+    assert!(z.data.column(2).iter().all(|x| x.is_nan()));
+
+    let z = df.diff(1, UtahAxis::Column).as_df().unwrap();
+    assert_eq!(z.data.column(0).slice(s![1..]), arr1(&[1., 2.]));
+    assert!(z.data[[1, 1]].is_nan() && z.data[[2, 1]].is_nan());
+    assert_eq!(z.data[[2, 2]], -8.);
+
+    let z = df.pct_change(1, UtahAxis::Column).as_df().unwrap();
+    assert_eq!(z.data.column(0).slice(s![1..]), arr1(&[1., 1.]));
+    assert_eq!(z.data.column(2).slice(s![1..]), arr1(&[1., -1.]));
+
+    let z = df.df_iter(UtahAxis::Column).select(&["a", "c"]).diff(2).as_df().unwrap();
+    assert_eq!(z.columns, vec!["a", "c"]);
+    assert_eq!(z.data[[2, 0]], 3.);
+    assert_eq!(z.data[[2, 1]], -4.);
+
+    let b = arr2(&[[0, 2], [3, 4]]);
+    let df: DataFrame<i32> = DataFrame::new(b).columns(&["a", "b"]).unwrap();
+    let z = df.pct_change(1, UtahAxis::Column).as_matrix().unwrap();
+    assert_eq!(z, arr2(&[[0, 0], [0, 1]]));
+}
+
+#[test]
+fn dataframe_transpose() {
+    let a = arr2(&[[1., 2.], [3., 4.], [5., 6.]]);
+    let df: DataFrame<f64> = DataFrame::new(a)
+        .columns(&["a", "b"])
+        .unwrap()
+        .index(&["x", "y", "z"])
+        .unwrap();
+    let z = df.t();
+    assert_eq!(z.data.dim(), (2, 3));
+    assert_eq!(z.index, vec!["a", "b"]);
+    assert_eq!(z.columns, vec!["x", "y", "z"]);
+    assert_eq!(z.data, arr2(&[[1., 3., 5.], [2., 4., 6.]]));
+    assert_eq!(z.clone().transpose(), df);
+
+    let z = z.select(&["y"], UtahAxis::Column).as_df().unwrap();
+    assert_eq!(z.data, arr2(&[[3.], [4.]]));
+
+    let mut df = df;
+    let m = df.mean(UtahAxis::Column).as_df().unwrap();
+    let z = df.mean(UtahAxis::Column).as_transposed_df().unwrap();
+    assert_eq!(z.index, m.columns);
+    assert_eq!(z.columns, m.index);
+    assert_eq!(z.data, m.data.t());
+}
+
+#[test]
+fn dataframe_head_tail_slice_take() {
+    let a = arr2(&[[1., 2., 3.], [4., 5., 6.], [7., 8., 9.], [10., 11., 12.]]);
+    let df: DataFrame<f64> = DataFrame::new(a)
+        .columns(&["a", "b", "c"])
+        .unwrap()
+        .index(&["w", "x", "y", "z"])
+        .unwrap();
+
+    let z = df.head(2);
+    assert_eq!(z.index, vec!["w", "x"]);
+    assert_eq!(z.data, arr2(&[[1., 2., 3.], [4., 5., 6.]]));
+    assert_eq!(df.head(10), df);
+
+    let z = df.tail(1);
+    assert_eq!(z.index, vec!["z"]);
+    assert_eq!(z.data, arr2(&[[10., 11., 12.]]));
+    assert_eq!(df.tail(0).data.dim(), (0, 3));
+
+    let z = df.slice(1..3, UtahAxis::Column);
+    assert_eq!(z.columns, vec!["b", "c"]);
+    assert_eq!(z.index, df.index);
+    assert_eq!(z.data, arr2(&[[2., 3.], [5., 6.], [8., 9.], [11., 12.]]));
+    assert_eq!(df.slice(3..8, UtahAxis::Row).index, vec!["z"]);
+
+    let z = df.take(&[2, 0, 2], UtahAxis::Row).unwrap();
+    assert_eq!(z.index, vec!["y", "w", "y"]);
+    assert_eq!(z.data, arr2(&[[7., 8., 9.], [1., 2., 3.], [7., 8., 9.]]));
+
+    let z = df.take(&[2, 0], UtahAxis::Column).unwrap();
+    assert_eq!(z.columns, vec!["c", "a"]);
+    assert_eq!(z.data, arr2(&[[3., 1.], [6., 4.], [9., 7.], [12., 10.]]));
+
+    assert!(df.take(&[3], UtahAxis::Column).is_err());
+}
+
+#[test]
+fn dataframe_sample_shuffle_split() {
+    let a = arr2(&[[1., 0.], [2., 0.], [3., 0.], [4., 0.], [5., 1.], [6., 1.], [7., 1.], [8., 1.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["x", "y"]).unwrap();
+
+    let z = df.sample(SampleSize::N(3), false, 7).unwrap();
+    assert_eq!(z, df.sample(SampleSize::N(3), false, 7).unwrap());
+    assert_eq!(z.data.dim(), (3, 2));
+    let mut seen: Vec<String> = z.index.clone();
+    seen.sort();
+    seen.dedup();
+    assert_eq!(seen.len(), 3);
+    for (label, row) in z.index.iter().zip(z.data.outer_iter()) {
+        let i: usize = label.parse().unwrap();
+        assert_eq!(row, df.data.row(i));
+    }
+
+    let z = df.sample(SampleSize::Frac(2.), true, 7).unwrap();
+    assert_eq!(z.data.dim(), (16, 2));
+    assert!(df.sample(SampleSize::N(9), false, 7).is_err());
+
+    let z = df.shuffle(3);
+    assert_eq!(z, df.shuffle(3));
+    let mut index = z.index;
+    index.sort();
+    assert_eq!(index, df.index);
+
+    let (train, test) = df.train_test_split(0.5, Some("y"), 11).unwrap();
+    assert_eq!(train.data.dim(), (4, 2));
+    assert_eq!(test.data.dim(), (4, 2));
+    assert_eq!(train.data.column(1).iter().filter(|x| **x == 1.).count(), 2);
+    let mut index: Vec<String> = train.index.iter().chain(test.index.iter()).cloned().collect();
+    index.sort();
+    assert_eq!(index, df.index);
+    assert!(df.train_test_split(0.5, Some("z"), 11).is_err());
+    assert!(df.train_test_split(1.5, None, 11).is_err());
+}
+
+#[test]
+fn dataframe_random_generators() {
+    let df: DataFrame<f64> = DataFrame::random((4, 3), Range::new(0., 10.), 5);
+    assert_eq!(df.data.dim(), (4, 3));
+    assert!(df.data.iter().all(|x| *x >= 0. && *x < 10.));
+    assert_eq!(df, DataFrame::random((4, 3), Range::new(0., 10.), 5));
+    assert!(df != DataFrame::random((4, 3), Range::new(0., 10.), 6));
+
+    let df: DataFrame<i32> = DataFrame::from_fn((2, 3), |i, j| (i * 10 + j) as i32)
+        .columns(&["a", "b", "c"])
+        .unwrap();
+    assert_eq!(df.data, arr2(&[[0, 1, 2], [10, 11, 12]]));
+    assert_eq!(df.index, vec!["0", "1"]);
+
+    let df = DataFrame::random_mixed((200, 6), 0.25, 9).unwrap();
+    assert_eq!(df, DataFrame::random_mixed((200, 6), 0.25, 9).unwrap());
+    let empties = df.data.iter().filter(|x| **x == InnerType::Empty).count();
+    assert!(empties > 200 && empties < 400);
+    assert!(DataFrame::random_mixed((10, 4), 0., 9).unwrap().data.iter().all(|x| *x != InnerType::Empty));
+    assert!(DataFrame::random_mixed((10, 4), 1.5, 9).is_err());
+}
+
+#[test]
+fn dataframe_duplicates() {
+    let a = arr2(&[[1., 2.], [1., 3.], [1., 2.], [NAN, 4.], [NAN, 4.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+
+    let mask: Vec<bool> = df.duplicated(None, Keep::First).unwrap().into_iter().map(|x| x.1).collect();
+    assert_eq!(mask, vec![false, false, true, false, true]);
+    let mask: Vec<bool> = df.duplicated(None, Keep::Last).unwrap().into_iter().map(|x| x.1).collect();
+    assert_eq!(mask, vec![true, false, false, true, false]);
+    let mask = df.duplicated(Some(&["a"]), Keep::None).unwrap();
+    assert_eq!(mask[1], ("1".to_string(), true));
+    assert_eq!(mask.iter().filter(|x| x.1).count(), 5);
+
+    let z = df.drop_duplicates(None, Keep::First).unwrap();
+    assert_eq!(z.index, vec!["0", "1", "3"]);
+    let z = df.drop_duplicates(Some(&["a"]), Keep::Last).unwrap();
+    assert_eq!(z.index, vec!["2", "4"]);
+    assert!(df.drop_duplicates(Some(&["c"]), Keep::First).is_err());
+
+    let u = df.unique("a").unwrap();
+    assert_eq!(u.len(), 2);
+    assert_eq!(u[0], 1.);
+    assert!(u[1].is_nan());
+
+    let b = arr2(&[[InnerType::Str("x".into()), InnerType::Int32(1)],
+                   [InnerType::Str("y".into()), InnerType::Int32(1)],
+                   [InnerType::Str("x".into()), InnerType::Int32(1)]]);
+    let df: DataFrame<InnerType> = DataFrame::new(b).columns(&["a", "b"]).unwrap();
+    assert_eq!(df.unique("a").unwrap(),
+               arr1(&[InnerType::Str("x".into()), InnerType::Str("y".into())]));
+    assert_eq!(df.drop_duplicates(None, Keep::None).unwrap().index, vec!["1"]);
+}
+
+#[test]
+fn hash_key_order() {
+    let mut keys: Vec<HashKey> = [2., -1., 0.5, -3., NAN].iter().map(|x| x.hash_key()).collect();
+    keys.extend(vec![InnerType::Int64(-2).hash_key(), InnerType::Int64(2).hash_key()]);
+    keys.sort();
+    assert_eq!(keys,
+               vec![(-3f64).hash_key(),
+                    InnerType::Int64(-2).hash_key(),
+                    (-1f64).hash_key(),
+                    0.5f64.hash_key(),
+                    2f64.hash_key(),
+                    InnerType::Int64(2).hash_key(),
+                    HashKey::Empty]);
+    assert!(1f64.hash_key() != InnerType::Int64(1).hash_key());
+}
+
+#[test]
+fn dataframe_value_counts_crosstab() {
+    let a = arr2(&[[InnerType::Str("x".into()), InnerType::Int32(1)],
+                   [InnerType::Str("y".into()), InnerType::Int32(2)],
+                   [InnerType::Str("y".into()), InnerType::Int32(1)],
+                   [InnerType::Empty, InnerType::Int32(2)],
+                   [InnerType::Str("y".into()), InnerType::Int32(1)]]);
+    let df: DataFrame<InnerType> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+
+    let z = df.value_counts("a", false, true).unwrap();
+    assert_eq!(z.columns, vec![OuterType::from("count")]);
+    assert_eq!(z.index, vec![OuterType::from("y"), OuterType::from("x")]);
+    assert_eq!(z.data, arr2(&[[3.], [1.]]));
+
+    let z = df.value_counts("b", true, false).unwrap();
+    assert_eq!(z.columns, vec![OuterType::from("proportion")]);
+    assert_eq!(z.index, vec![OuterType::Int64(1), OuterType::Int64(2)]);
+    assert_eq!(z.data, arr2(&[[0.6], [0.4]]));
+    assert!(df.value_counts("c", false, false).is_err());
+
+    let z = crosstab(&df, "a", "b").unwrap();
+    assert_eq!(z.index, vec![OuterType::from("x"), OuterType::from("y")]);
+    assert_eq!(z.columns, vec![OuterType::Int64(1), OuterType::Int64(2)]);
+    assert_eq!(z.data, arr2(&[[1., 0.], [2., 1.]]));
+
+    let b = arr2(&[[1.5], [2.], [1.5]]);
+    let df: DataFrame<f64> = DataFrame::new(b).columns(&["a"]).unwrap();
+    assert_eq!(df.value_counts("a", false, true).unwrap().index,
+               vec![OuterType::from(1.5), OuterType::from(2.)]);
+}
+
+#[test]
+fn dataframe_pivot_melt() {
+    let a = arr2(&[[1., 10., 5.], [1., 20., 6.], [2., 10., 7.], [2., 10., 9.]]);
+    let df: DataFrame<f64> = DataFrame::new(a).columns(&["day", "site", "temp"]).unwrap();
+
+    match df.pivot("day", "site", "temp") {
+        Err(Error(ErrorKind::DuplicateEntry(i, c), _)) => {
+            assert_eq!((i, c), ("2".to_string(), "10".to_string()))
+        }
+        _ => panic!("expected a duplicate entry"),
+    }
+    let c = arr2(&[[1., 10., 5.], [2., 20., 6.], [2., 20., 7.], [1., 10., 9.]]);
+    let twice: DataFrame<f64> = DataFrame::new(c).columns(&["day", "site", "temp"]).unwrap();
+    match twice.pivot("day", "site", "temp") {
+        Err(Error(ErrorKind::DuplicateEntry(i, c), _)) => {
+            assert_eq!((i, c), ("2".to_string(), "20".to_string()))
+        }
+        _ => panic!("expected a duplicate entry"),
+    }
+    let z = df.head(3).pivot("day", "site", "temp").unwrap();
+    assert_eq!(z.index.names, vec!["day"]);
+    assert_eq!(z.index.level_values(0), vec![OuterType::from(1.), OuterType::from(2.)]);
+    assert_eq!(z.columns.flatten("|"), vec!["10", "20"]);
+    assert_eq!(z.data[[0, 0]], 5.);
+    assert!(z.data[[1, 1]].is_nan());
+
+    let z = df.pivot_table("day", "site", "temp", AggFunc::Mean, Some(0.)).unwrap();
+    assert_eq!(z.data, arr2(&[[5., 6.], [8., 0.]]));
+    let z = df.pivot_table("day", "site", "temp", AggFunc::Count, None).unwrap();
+    assert_eq!(z.data[[1, 0]], 2.);
+    assert!(z.data[[1, 1]].is_nan());
+    let z = df.pivot_table("day", "site", "temp", AggFunc::Max, Some(0.)).unwrap();
+    assert_eq!(z.data, arr2(&[[5., 6.], [9., 0.]]));
+    let z = df.pivot_table("day", "site", "temp", AggFunc::Min, None).unwrap();
+    assert_eq!(z.data[[1, 0]], 7.);
+
+    let b = arr2(&[[1., 5., 6.], [2., 7., 8.]]);
+    let wide: DataFrame<f64> = DataFrame::new(b).columns(&["day", "a", "b"]).unwrap();
+    let z = wide.melt(&["day"], None).unwrap();
+    assert_eq!(z.columns, vec!["day", "variable", "value"]);
+    assert_eq!(z.data.dim(), (4, 3));
+    assert_eq!(z.data.row(2),
+               arr1(&[InnerType::Float(1.), InnerType::Str("b".into()), InnerType::Float(6.)]));
+    let z = wide.melt(&[], Some(&["a"])).unwrap();
+    assert_eq!(z.data.column(0),
+               arr1(&[InnerType::Str("a".into()), InnerType::Str("a".into())]));
+}
+
+#[test]
+fn dataframe_multiindex() {
+    let a = arr2(&[[2016., 1., 5., 50.],
+                   [2016., 2., 6., 60.],
+                   [2017., 1., 7., 70.],
+                   [2017., 1., 9., 90.]]);
+    let df: DataFrame<f64> = DataFrame::new(a)
+        .columns(&["year", "site", "temp", "rain"])
+        .unwrap();
+
+    let z = df.set_index(&["year", "site"]).unwrap();
+    assert_eq!(z.index.names, vec!["year", "site"]);
+    assert_eq!(z.index.keys[1], vec![OuterType::from(2016.), OuterType::from(2.)]);
+    assert_eq!(z.columns.level_values(0),
+               vec![OuterType::from("temp"), OuterType::from("rain")]);
+    let y = z.loc(&[OuterType::from(2017.), OuterType::from(1.)]).unwrap();
+    assert_eq!(y.index.nlevels(), 0);
+    assert_eq!(y.data, arr2(&[[7., 70.], [9., 90.]]));
+    assert!(z.unstack().is_err());
+
+    let g = df.groupby(&["year", "site"], AggFunc::Sum).unwrap();
+    assert_eq!(g.index.len(), 3);
+    assert_eq!(g.data.row(2), arr1(&[16., 160.]));
+
+    let u = g.unstack().unwrap();
+    assert_eq!(u.index.names, vec!["year"]);
+    assert_eq!(u.columns.names, vec!["columns", "site"]);
+    assert_eq!(u.columns.flatten("|"), vec!["temp|1", "temp|2", "rain|1", "rain|2"]);
+    assert_eq!(u.data.row(0), arr1(&[5., 6., 50., 60.]));
+    assert!(u.data[[1, 1]].is_nan());
+    assert_eq!(u.data[[1, 2]], 160.);
+
+    let s = u.stack().unwrap();
+    assert_eq!(s.index.names, vec!["year", "site"]);
+    assert_eq!(s.index.len(), 4);
+    assert_eq!(s.data.row(1), arr1(&[6., 60.]));
+    assert!(s.data.row(3).iter().all(|x| x.is_nan()));
+
+    let r = g.reset_index().unwrap();
+    assert_eq!(r.columns, vec!["year", "site", "temp", "rain"]);
+    assert_eq!(r.data.row(0),
+               arr1(&[InnerType::Float(2016.),
+                      InnerType::Float(1.),
+                      InnerType::Float(5.),
+                      InnerType::Float(50.)]));
+    assert_eq!(g.to_df("/").unwrap().index, vec!["2016/1", "2016/2", "2017/1"]);
+
+    let b = arr2(&[[InnerType::Int32(1), InnerType::Bool(true), InnerType::Float(2.)],
+                   [InnerType::Int32(1), InnerType::Bool(false), InnerType::Float(3.)]]);
+    let df: DataFrame<InnerType> = DataFrame::new(b).columns(&["k", "flag", "v"]).unwrap();
+    let z = df.set_index(&["k", "flag"]).unwrap();
+    assert_eq!(z.index.keys[1], vec![OuterType::Int64(1), OuterType::Bool(false)]);
+    assert_eq!(z.reset_index().unwrap().data.row(0),
+               arr1(&[InnerType::Int64(1), InnerType::Bool(true), InnerType::Float(2.)]));
+}
+
+#[test]
+fn dataframe_element_types() {
+    let mut df: DataFrame<i64> = DataFrame::new(arr2(&[[2, 6], [3, 4]]));
+    assert_eq!(df.sumdf(UtahAxis::Column).as_array().unwrap(), arr1(&[5, 10]));
+    let mut df: DataFrame<u64> = DataFrame::new(arr2(&[[2u64, 6], [3, 4]]));
+    assert_eq!(df.sumdf(UtahAxis::Row).as_array().unwrap(), arr1(&[8, 7]));
+    let mut df: DataFrame<f32> = DataFrame::new(arr2(&[[2f32, 6.], [3., 4.]]));
+    assert_eq!(df.mean(UtahAxis::Column).as_array().unwrap(), arr1(&[2.5, 5.]));
+
+    let parsed: Vec<InnerType> = ["true", "-3", "18446744073709551615", "1.5", "x"]
+        .iter()
+        .map(|x| x.parse().unwrap())
+        .collect();
+    assert_eq!(parsed,
+               vec![InnerType::Bool(true),
+                    InnerType::Int64(-3),
+                    InnerType::UInt64(u64::max_value()),
+                    InnerType::Float(1.5),
+                    InnerType::from("x")]);
+    assert_eq!(InnerType::UInt64(1) - InnerType::UInt64(2), InnerType::Empty);
+    assert_eq!(InnerType::from(1.5f32) + InnerType::from(1f32), InnerType::Float32(2.5));
+    assert_eq!(InnerType::from(true) + InnerType::from(true), InnerType::Empty);
+    assert_eq!(InnerType::from(true).to_f64(), Some(1.));
+    assert_eq!(InnerType::UInt64(7).hash_key(), InnerType::Int64(7).hash_key());
+
+    assert_eq!(InnerType::Int64(1) + InnerType::Float(0.5), InnerType::Float(1.5));
+    assert_eq!(InnerType::Float(3.) / InnerType::Int32(2), InnerType::Float(1.5));
+    assert!(InnerType::Int64(2) > InnerType::Float(1.5));
+    let mut df: DataFrame<InnerType> = DataFrame::new(arr2(&[["1".parse::<InnerType>().unwrap()],
+                                                             [InnerType::Float(2.5)]]));
+    assert_eq!(df.sumdf(UtahAxis::Column).as_array().unwrap(), arr1(&[InnerType::Float(3.5)]));
+    let df: DataFrame<i64> = DataFrame::new(arr2(&[[0], [0]]));
+    assert_eq!(df.countdf(UtahAxis::Column).as_array().unwrap(), arr1(&[2]));
+}
+
+#[test]
+fn dataframe_integer_labels() {
+    let a = arr2(&[[1., 2.], [3., 4.], [5., 6.]]);
+    let df: DataFrame<f64, i64> = DataFrame::new(a).index(&[10, 2, 1]).unwrap();
+    assert_eq!(df.columns, vec![0, 1]);
+
+    let mut index = df.index.clone();
+    index.sort();
+    assert_eq!(index, vec![1, 2, 10]);
+
+    let res = df.select(&[&2, &10], UtahAxis::Row).as_df().unwrap();
+    assert_eq!(res.index, vec![10, 2]);
+    assert_eq!(res.data, arr2(&[[1., 2.], [3., 4.]]));
+
+    let res = df.shift(1, UtahAxis::Column).as_df().unwrap();
+    assert_eq!(res.index, vec![10, 2, 1]);
+    assert_eq!(res.data.row(2), arr1(&[3., 4.]));
+
+    let res = df.idxmax(UtahAxis::Column).as_df().unwrap();
+    assert_eq!(res.columns, vec![0, 1]);
+    assert_eq!(res.data.row(0), arr1(&[InnerType::Int64(1), InnerType::Int64(1)]));
+
+    let a = arr2(&[[1., 2.], [3., 4.]]);
+    let df: DataFrame<f64, OuterType> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    assert_eq!(df.index, vec![OuterType::USize(0), OuterType::USize(1)]);
+    let res = df.select(&["b"], UtahAxis::Column).as_df().unwrap();
+    assert_eq!(res.columns, vec![OuterType::from("b")]);
+    assert_eq!(res.data, arr2(&[[2.], [4.]]));
+    let res = df.select(&[&1i64], UtahAxis::Row).as_df().unwrap();
+    assert_eq!(res.data, arr2(&[[3., 4.]]));
+    let res = df.idxmin(UtahAxis::Row).as_df().unwrap();
+    assert_eq!(res.data.column(0).to_owned(),
+               arr1(&[InnerType::from("a"), InnerType::from("a")]));
+    let res = df.idxmax(UtahAxis::Column).as_array().unwrap();
+    assert_eq!(res, arr1(&[InnerType::Int64(1), InnerType::Int64(1)]));
+    let mut labels = vec![OuterType::Int64(10), OuterType::Int32(2), OuterType::USize(3),
+                          OuterType::from(-1.5), OuterType::from("a")];
+    labels.sort();
+    assert_eq!(labels,
+               vec![OuterType::from(-1.5), OuterType::Int32(2), OuterType::USize(3),
+                    OuterType::Int64(10), OuterType::from("a")]);
+    assert_eq!(OuterType::UInt64(2), OuterType::Int32(2));
+    assert!(OuterType::from(2.) != OuterType::Int32(2));
+    let ids: HashMap<OuterType, usize> = vec![(OuterType::USize(7), 0)].into_iter().collect();
+    assert_eq!(ids.get(&OuterType::Int64(7)), Some(&0));
+
+    let a = arr2(&[[1., 2.], [3., 4.], [1., 2.]]);
+    let df: DataFrame<f64, i64> = DataFrame::new(a).index(&[10, 2, 1]).unwrap();
+    assert_eq!(df.drop_duplicates(None, Keep::First).unwrap().index, vec![10, 2]);
+    assert_eq!(df.unique("1").unwrap(), arr1(&[2., 4.]));
+    assert_eq!(df.corr(CorrelationMethod::Pearson).unwrap().index, vec![0, 1]);
+    assert_eq!(df.sample(SampleSize::N(3), false, 7).unwrap().index.len(), 3);
+    assert_eq!(df.value_counts("0", false, true).unwrap().index,
+               vec![OuterType::from(1.), OuterType::from(3.)]);
+    let ints: DataFrame<InnerType> = DataFrame::new(arr2(&[[InnerType::Int64(10)],
+                                                           [InnerType::Int64(2)],
+                                                           [InnerType::Int64(10)]]));
+    assert_eq!(ints.value_counts("0", false, true).unwrap().index,
+               vec![OuterType::Int64(10), OuterType::Int64(2)]);
+    let labeled: DataFrame<f64, OuterType> = DataFrame::new(arr2(&[[1., 2.]]))
+        .columns(&[OuterType::Int64(5), OuterType::from("b")])
+        .unwrap();
+    let summary = labeled.describe().unwrap();
+    assert_eq!(summary.columns, labeled.columns);
+    assert_eq!(summary.index[1], OuterType::from("mean"));
+    assert_eq!(df.set_index(&["0"]).unwrap().columns.flatten("|"), vec!["1"]);
+}
+
+#[test]
+fn datetime_index() {
+    let d = Datetime::parse("29/02/2016 23:30:00 -05:00", "%d/%m/%Y %H:%M:%S %z").unwrap();
+    assert_eq!((d.year(), d.month(), d.day(), d.hour(), d.minute()), (2016, 2, 29, 23, 30));
+    assert_eq!(d.offset(), -5 * 3600);
+    assert_eq!(d, "2016-03-01T04:30:00Z".parse().unwrap());
+    assert_eq!(d.to_string(), "2016-02-29 23:30:00-05:00");
+    assert_eq!(Datetime::from_ymd(1969, 12, 31).unwrap().timestamp(), -86400);
+    assert!(Datetime::parse("2016-13-01", "%Y-%m-%d").is_err());
+    assert!("yesterday".parse::<Datetime>().is_err());
+    assert_eq!(d.with_offset(3600).unwrap().hour(), 5);
+    assert!(d.with_offset(-86400).is_err());
+
+    let a = arr2(&[[InnerType::from("2016-01-01"), InnerType::Float(1.)],
+                   [InnerType::from("2016-01-31 12:00"), InnerType::Float(2.)],
+                   [InnerType::from("2016-02-01"), InnerType::Float(3.)],
+                   [InnerType::from("2016-03-06"), InnerType::Float(4.)]]);
+    let raw: DataFrame<InnerType> = DataFrame::new(a).columns(&["date", "x"]).unwrap();
+    assert!(raw.to_datetime_index("x").is_err());
+    let df = raw.to_datetime_index("date").unwrap();
+    assert_eq!(df.columns, vec![OuterType::from("x")]);
+
+    let index = df.datetime_index().unwrap();
+    assert_eq!(index.year(), vec![2016, 2016, 2016, 2016]);
+    assert_eq!(index.month(), vec![1, 1, 2, 3]);
+    assert_eq!(index.weekday(), vec![4, 6, 0, 6]);
+
+    let res = df.loc_range("2016-01-01".."2016-02-01").unwrap();
+    assert_eq!(res.data, arr2(&[[InnerType::Float(1.)], [InnerType::Float(2.)]]));
+    assert!(df.loc_range("2016-01-01".."February").is_err());
+}
+
+#[test]
+fn dataframe_modify() {
+    let a = arr2(&[[1., 2.], [3., 4.], [5., 6.]]);
+    let mut df: DataFrame<f64> = DataFrame::new(a).columns(&["a", "b"]).unwrap();
+    df.insert_column("c", 0, arr1(&[7., 8., 9.]).view()).unwrap();
+    assert_eq!(df.columns, vec!["c", "a", "b"]);
+    match df.insert_column("d", 0, arr1(&[1., 2.]).view()) {
+        Err(Error(ErrorKind::IndexShapeMismatch(ref expected, ref actual), _)) => {
+            assert_eq!((&expected[..], &actual[..]), ("3", "2"))
+        }
+        _ => panic!(),
+    }
+    assert!(df.insert_column("d", 4, arr1(&[1., 2., 3.]).view()).is_err());
+    assert!(df.insert_column("a", 0, arr1(&[1., 2., 3.]).view()).is_err());
+
+    df.set_column("a", arr1(&[0., 0., 0.]).view()).unwrap();
+    df.set_column("e", arr1(&[1., 1., 1.]).view()).unwrap();
+    assert_eq!(df.data,
+               arr2(&[[7., 0., 2., 1.], [8., 0., 4., 1.], [9., 0., 6., 1.]]));
+    assert!(df.set_column("a", arr1(&[1.]).view()).is_err());
+
+    df.rename(&[("0", "x"), ("2", "z")], UtahAxis::Row).unwrap();
+    df.rename(&[("e", "ones")], UtahAxis::Column).unwrap();
+    assert_eq!(df.index, vec!["x", "1", "z"]);
+    assert_eq!(df.columns, vec!["c", "a", "b", "ones"]);
+    match df.rename(&[("q", "r")], UtahAxis::Row) {
+        Err(Error(ErrorKind::InvalidRowName(ref name), _)) => assert_eq!(name, "q"),
+        _ => panic!(),
+    }
+    match df.rename(&[("c", "b")], UtahAxis::Column) {
+        Err(Error(ErrorKind::DuplicateColumnName(ref name), _)) => assert_eq!(name, "b"),
+        _ => panic!(),
+    }
+    assert!(df.rename(&[("c", "y"), ("a", "y")], UtahAxis::Column).is_err());
+    df.rename(&[("c", "a"), ("a", "c")], UtahAxis::Column).unwrap();
+    assert_eq!(df.columns, vec!["a", "c", "b", "ones"]);
+    df.rename(&[("c", "a"), ("a", "c")], UtahAxis::Column).unwrap();
+    assert_eq!(df.columns, vec!["c", "a", "b", "ones"]);
+
+    df.drop(&["1"], UtahAxis::Row).unwrap();
+    df.drop(&["a", "ones"], UtahAxis::Column).unwrap();
+    assert_eq!(df.data, arr2(&[[7., 2.], [9., 6.]]));
+    assert_eq!(df.index, vec!["x", "z"]);
+    match df.drop(&["a"], UtahAxis::Column) {
+        Err(Error(ErrorKind::InvalidColumnName(ref name), _)) => assert_eq!(name, "a"),
+        _ => panic!(),
+    }
+    match df.drop(&["1"], UtahAxis::Row) {
+        Err(Error(ErrorKind::InvalidRowName(ref name), _)) => assert_eq!(name, "1"),
+        _ => panic!(),
+    }
+    assert_eq!(df.columns, vec!["c", "b"]);
+}
+
+#[test]
+fn dataframe_astype() {
+    let a = arr2(&[[InnerType::from("3"), InnerType::Float(1.5), InnerType::from("x")],
+                   [InnerType::from("-4"), InnerType::Empty, InnerType::Bool(true)]]);
+    let df: DataFrame<InnerType> = DataFrame::new(a).columns(&["a", "b", "c"]).unwrap();
+
+    let res = df.cast_column("a", DType::Int64, CastMode::Strict).unwrap();
+    assert_eq!(res.data.column(0).to_owned(),
+               arr1(&[InnerType::Int64(3), InnerType::Int64(-4)]));
+    assert_eq!(res.data.column(1), df.data.column(1));
+    let res = df.cast_column("a", DType::UInt64, CastMode::Lossy).unwrap();
+    assert_eq!(res.data[[1, 0]], InnerType::Empty);
+    match df.cast_column("c", DType::Float, CastMode::Strict) {
+        Err(Error(ErrorKind::InvalidCast(_, ref column), _)) => assert_eq!(column, "c"),
+        _ => panic!(),
+    }
+    assert!(df.cast_column("d", DType::Float, CastMode::Strict).is_err());
+    assert!(df.cast_column("b", DType::Int64, CastMode::Strict).is_err());
+    assert_eq!(df.cast_column("b", DType::Int64, CastMode::Lossy).unwrap().data[[0, 1]],
+               InnerType::Int64(1));
+    let strs: DataFrame<InnerType> = DataFrame::new(arr2(&[[InnerType::from("1.5")],
+                                                           [InnerType::from("true")]]));
+    assert!(strs.slice(0..1, UtahAxis::Row).astype::<i64>(CastMode::Strict).is_err());
+    assert!(strs.slice(1..2, UtahAxis::Row).astype::<f64>(CastMode::Strict).is_err());
+    assert!(DataFrame::<f64>::new(arr2(&[[2.5]])).astype::<i32>(CastMode::Strict).is_err());
+
+    assert!(df.astype::<i64>(CastMode::Strict).is_err());
+    let res = df.astype::<i64>(CastMode::Lossy).unwrap();
+    assert_eq!(res.data, arr2(&[[3, 1, 0], [-4, 0, 1]]));
+    assert_eq!(res.columns, df.columns);
+    let back: DataFrame<InnerType> = res.astype(CastMode::Strict).unwrap();
+    assert_eq!(back.data[[0, 1]], InnerType::Int64(1));
+    let floats: DataFrame<f32> = res.astype(CastMode::Strict).unwrap();
+    assert_eq!(floats.data[[1, 0]], -4.);
+
+    let gaps: DataFrame<f64> = DataFrame::new(arr2(&[[NAN, 2.]]));
+    match gaps.astype::<i64>(CastMode::Strict) {
+        Err(Error(ErrorKind::InvalidCast(ref value, _), _)) => assert_eq!(value, "NaN"),
+        _ => panic!(),
+    }
+    assert!(gaps.astype::<u64>(CastMode::Strict).is_err());
+    assert!(gaps.astype::<f32>(CastMode::Strict).unwrap().data[[0, 0]].is_nan());
+    let empty: DataFrame<InnerType> = DataFrame::new(arr2(&[[InnerType::Empty]]));
+    assert!(empty.astype::<u64>(CastMode::Strict).is_err());
+    assert_eq!(empty.astype::<u64>(CastMode::Lossy).unwrap().data[[0, 0]], 0);
+}
+
+#[test]
+fn column_frame() {
+    let a = arr2(&[[InnerType::from("NY"), InnerType::Int64(3), InnerType::Float(1.5)],
+                   [InnerType::from("CA"), InnerType::Int64(5), InnerType::Empty],
+                   [InnerType::Empty, InnerType::Int64(7), InnerType::Int32(2)]]);
+    let df: DataFrame<InnerType> = DataFrame::new(a)
+        .columns(&["state", "n", "x"])
+        .unwrap()
+        .index(&["a", "b", "c"])
+        .unwrap();
+    let cf = ColumnFrame::from_df(&df);
+    let dtypes: Vec<DType> = cf.data.iter().map(|x| x.dtype()).collect();
+    assert_eq!(dtypes, vec![DType::Str, DType::Int64, DType::Float]);
+    assert_eq!(cf.data[1], Column::Int64(arr1(&[3, 5, 7])));
+
+    let res = cf.select(&["c", "a"], UtahAxis::Row).unwrap();
+    assert_eq!(res.index, vec!["c", "a"]);
+    assert_eq!(res.data[0], Column::Str(vec![None, Some("NY".to_string())]));
+    let res = cf.remove(&["state"], UtahAxis::Column).unwrap();
+    assert_eq!(res.columns, vec!["n", "x"]);
+    assert!(cf.remove(&["d"], UtahAxis::Row).is_err());
+
+    let other = ColumnFrame::new(vec![Column::Int32(arr1(&[10, 20]))])
+        .unwrap()
+        .columns(&["y"])
+        .unwrap()
+        .index(&["c", "a"])
+        .unwrap();
+    let res = cf.inner_join(&other);
+    assert_eq!(res.index, vec!["a", "c"]);
+    assert_eq!(res.data[3], Column::Int32(arr1(&[20, 10])));
+    let res = cf.outer_join(&other);
+    assert_eq!(res.shape(), (3, 4));
+    assert_eq!(res.data[3].to_inner(),
+               vec![InnerType::Float(20.), InnerType::Empty, InnerType::Float(10.)]);
+
+    let res = cf.agg(AggFunc::Sum).unwrap();
+    assert_eq!(res.data.row(0).to_owned(),
+               arr1(&[InnerType::Empty, InnerType::Int64(15), InnerType::Float(3.5)]));
+    let res = cf.agg(AggFunc::Count).unwrap();
+    assert_eq!(res.data[[0, 0]].to_f64(), Some(2.));
+    let res = cf.agg(AggFunc::Mode).unwrap();
+    assert_eq!(res.data[[0, 1]], InnerType::Int64(3));
+    let res = cf.agg(AggFunc::Mean).unwrap();
+    assert_eq!(res.data[[0, 1]], InnerType::Float(5.));
+
+    let zeros = Column::Int32(arr1(&[0, 2]));
+    assert_eq!(zeros.agg(AggFunc::Mean), InnerType::Float(1.));
+    assert_eq!(zeros.agg(AggFunc::Count), InnerType::Int64(2));
+    assert_eq!(zeros.agg(AggFunc::Sum), InnerType::Int64(2));
+    assert_eq!(Column::Int64(arr1(&[0, 2])).agg(AggFunc::Count), InnerType::Int64(2));
+    assert_eq!(Column::Float(arr1(&[NAN, 2.])).agg(AggFunc::Mean), InnerType::Float(2.));
+}
+
+#[test]
+fn dataframe_schema() {
+    let a = arr2(&[[InnerType::from("ann"), InnerType::Int64(31), InnerType::Float(1.5)],
+                   [InnerType::from("bob"), InnerType::Empty, InnerType::Int64(2)]]);
+    let df: DataFrame<InnerType> = DataFrame::new(a)
+        .columns(&["name", "age", "score"])
+        .unwrap()
+        .index(&["a", "b"])
+        .unwrap();
+    let schema = df.schema();
+    assert_eq!(schema,
+               Schema::new()
+                   .field("name", DType::Str, false)
+                   .field("age", DType::Int64, true)
+                   .any_field("score", false));
+    assert!(df.validate(&schema).is_ok());
+
+    let strict = Schema::new()
+        .field("name", DType::Str, false)
+        .field("age", DType::Int64, false)
+        .any_field("score", false);
+    match df.validate(&strict) {
+        Err(Error(ErrorKind::UnexpectedEmpty(ref column, ref index), _)) => {
+            assert_eq!((&column[..], &index[..]), ("age", "b"))
+        }
+        _ => panic!(),
+    }
+    let typed = schema.clone().field("extra", DType::Bool, true);
+    match df.validate(&typed) {
+        Err(Error(ErrorKind::MissingColumn(ref column), _)) => assert_eq!(column, "extra"),
+        _ => panic!(),
+    }
+    let mut fewer = schema.clone();
+    fewer.fields.pop();
+    match df.validate(&fewer) {
+        Err(Error(ErrorKind::ExtraColumn(ref column), _)) => assert_eq!(column, "score"),
+        _ => panic!(),
+    }
+    let mut wrong = schema;
+    wrong.fields[2].dtype = Some(DType::Float);
+    match df.validate(&wrong) {
+        Err(Error(ErrorKind::ColumnTypeMismatch(ref column, ref expected, ref actual), _)) => {
+            assert_eq!((&column[..], &expected[..], &actual[..]), ("score", "float", "int64"))
+        }
+        _ => panic!(),
+    }
+
+    let floats: DataFrame<f64> = DataFrame::new(arr2(&[[1., NAN]])).columns(&["x", "y"]).unwrap();
+    assert_eq!(floats.schema(),
+               Schema::new().field("x", DType::Float, false).field("y", DType::Float, true));
+    assert_eq!(ColumnFrame::from_df(&df).schema().fields[2].dtype, Some(DType::Float));
+}
+
+#[test]
+fn read_csv_with_schema() {
+    let schema = |age: bool, score: DType| {
+        Schema::new()
+            .field("name", DType::Str, false)
+            .field("age", DType::Int64, age)
+            .field("score", score, false)
+    };
+    let df = DataFrame::read_csv_with_schema("src/tests/schema.csv", &schema(true, DType::Float))
+        .unwrap();
+    assert_eq!(df.data,
+               arr2(&[[InnerType::from("ann"), InnerType::Int64(31), InnerType::Float(1.5)],
+                      [InnerType::from("bob"), InnerType::Empty, InnerType::Float(2.)]]));
+
+    match DataFrame::read_csv_with_schema("src/tests/schema.csv", &schema(false, DType::Float)) {
+        Err(Error(ErrorKind::UnexpectedEmpty(ref column, ref index), _)) => {
+            assert_eq!((&column[..], &index[..]), ("age", "1"))
+        }
+        _ => panic!(),
+    }
+    match DataFrame::read_csv_with_schema("src/tests/schema.csv", &schema(true, DType::Int64)) {
+        Err(Error(ErrorKind::ColumnTypeMismatch(ref column, ref expected, ref actual), _)) => {
+            assert_eq!((&column[..], &expected[..], &actual[..]), ("score", "int64", "float"))
+        }
+        _ => panic!(),
+    }
+    assert_eq!(InnerType::Float(NAN).dtype(), None);
+}
+
+#[test]
+fn categorical() {
+    let a = arr2(&[[InnerType::from("NY"), InnerType::Float(1.)],
+                   [InnerType::from("CA"), InnerType::Float(2.)],
+                   [InnerType::Empty, InnerType::Float(3.)],
+                   [InnerType::from("NY"), InnerType::Float(4.)]]);
+    let df: DataFrame<InnerType> = DataFrame::new(a).columns(&["state", "x"]).unwrap();
+    let cat = df.to_categorical("state").unwrap();
+    assert_eq!(cat.categories(), &["CA", "NY"]);
+    assert_eq!(cat.eq_value("NY"), vec![true, false, false, true]);
+    assert_eq!(cat.eq_value("TX"), vec![false, false, false, false]);
+    assert_eq!(cat.groups(), vec![vec![1], vec![0, 3]]);
+    assert_eq!(cat.argsort(), vec![1, 0, 3, 2]);
+    assert_eq!(cat.to_inner(), df.data.column(0).to_owned().into_raw_vec());
+    assert!(df.to_categorical("city").is_err());
+
+    let taken = cat.take(&[3, 0]);
+    assert_eq!(taken, Categorical::new(&["NY", "NY"]));
+    assert_eq!(taken.categories(), cat.categories());
+
+    let sizes = Categorical::with_categories(&["M", "S", "L", "S"], &["S", "M", "L"], true)
+        .unwrap();
+    assert!(sizes.is_ordered());
+    assert_eq!(sizes.argsort(), vec![1, 3, 0, 2]);
+    assert!(Categorical::with_categories(&["S"], &["S", "S"], false).is_err());
+}
+
+#[test]
+fn dataframe_resample() {
+    let f = InnerType::Float;
+    let e = InnerType::Empty;
+    let a = arr2(&[[f(1.), f(10.)], [f(2.), e.clone()], [f(4.), f(30.)], [f(8.), f(40.)]]);
+    let dates: Vec<OuterType> =
+        ["2016-01-04 06:00", "2016-01-04 18:00", "2016-01-06 00:00", "2016-01-11 12:00"]
+            .iter()
+            .map(|x| OuterType::Datetime(x.parse().unwrap()))
+            .collect();
+    let df: DataFrame<InnerType, OuterType> = DataFrame::new(a)
+        .columns(&["x", "y"])
+        .unwrap()
+        .index(&dates[..])
+        .unwrap();
+    assert!("0h".parse::<TimeDelta>().is_err());
+    assert!("1M".parse::<TimeDelta>().is_err());
+    assert!("9223372036854775807W".parse::<TimeDelta>().is_err());
+    let years: Vec<OuterType> = ["2016-01-01", "2017-01-01"]
+        .iter()
+        .map(|x| OuterType::Datetime(x.parse().unwrap()))
+        .collect();
+    let long: DataFrame<f64, OuterType> =
+        DataFrame::new(arr2(&[[1.], [2.]])).index(&years[..]).unwrap();
+    match long.resample("1s").unwrap().agg(AggFunc::Sum) {
+        Err(Error(ErrorKind::TooManyBuckets(n, _), _)) => assert_eq!(n, 366 * 86400 + 1),
+        _ => panic!("expected too many buckets"),
+    }
+
+    let res = df.resample("1D").unwrap().agg(AggFunc::Sum).unwrap();
+    assert_eq!(res.index.len(), 8);
+    assert_eq!(res.index[2], OuterType::Datetime("2016-01-06".parse().unwrap()));
+    assert_eq!(res.data.row(0), arr1(&[f(3.), f(10.)]));
+    assert_eq!(res.data.row(1), arr1(&[e.clone(), e.clone()]));
+    let res = df.resample("1W").unwrap().agg(AggFunc::Mean).unwrap();
+    assert_eq!(res.data, arr2(&[[f(7. / 3.), f(20.)], [f(8.), f(40.)]]));
+    let res = df.resample("1W").unwrap().agg(AggFunc::Max).unwrap();
+    assert_eq!(res.data, arr2(&[[f(4.), f(30.)], [f(8.), f(40.)]]));
+    let res = df.resample("1W").unwrap().agg(AggFunc::Min).unwrap();
+    assert_eq!(res.data.row(0), arr1(&[f(1.), f(10.)]));
+
+    let df = df.loc_range("2016-01-04".."2016-01-07").unwrap();
+    let res = df.resample("12h").unwrap();
+    assert_eq!(res.asfreq().unwrap().data,
+               arr2(&[[e.clone(), e.clone()], [e.clone(), e.clone()], [e.clone(), e.clone()],
+                      [e.clone(), e.clone()], [f(4.), f(30.)]]));
+    assert_eq!(res.ffill().unwrap().data,
+               arr2(&[[e.clone(), e.clone()], [f(1.), f(10.)], [f(2.), f(10.)], [f(2.), f(10.)],
+                      [f(4.), f(30.)]]));
+    assert_eq!(res.bfill().unwrap().data,
+               arr2(&[[f(1.), f(10.)], [f(2.), f(30.)], [f(4.), f(30.)], [f(4.), f(30.)],
+                      [f(4.), f(30.)]]));
+    let res = res.interpolate().unwrap();
+    assert_eq!(res.data[[0, 0]], e);
+    assert_eq!(res.data[[1, 0]], f(1.5));
+    assert!((res.data[[3, 0]].to_f64().unwrap() - 3.2).abs() < 1e-9);
+
+    let index = date_range("2016-01-01", "2016-01-03", "1D").unwrap();
+    assert_eq!(index.day(), vec![1, 2, 3]);
+    assert!(date_range("2016-01-01", "2016-01-03", "daily").is_err());
+}
@@ -20,6 +20,10 @@ error_chain! {
             description("invalid column name")
             display("invalid column name: '{}'", t)
         }
+        InvalidRowName(t: String) {
+            description("invalid row name")
+            display("invalid row name: '{}'", t)
+        }
 
         RowShapeMismatch {
             description("row shape mismatch.")
@@ -42,6 +46,70 @@ error_chain! {
             description("Parsing Error.")
             display("Read failed. Parsing Error. {}", t)
         }
+        InvalidQuantile(t: String) {
+            description("invalid quantile.")
+            display("quantile must be between 0 and 1, got {}", t)
+        }
+        InvalidWindow(t: String) {
+            description("invalid window.")
+            display("invalid window: {}", t)
+        }
+        PositionOutOfBounds(position: usize, len: usize) {
+            description("position out of bounds.")
+            display("position {} out of bounds for axis of length {}", position, len)
+        }
+        InvalidLevelName(t: String) {
+            description("invalid level name")
+            display("invalid level name: '{}'", t)
+        }
+        DuplicateEntry(index: String, column: String) {
+            description("duplicate entry.")
+            display("duplicate entry for index '{}' and column '{}'", index, column)
+        }
+        NonDatetimeLabel(t: String) {
+            description("non-datetime index label")
+            display("index label '{}' is not a datetime", t)
+        }
+        DuplicateColumnName(t: String) {
+            description("duplicate column name")
+            display("duplicate column name: '{}'", t)
+        }
+        MissingColumn(t: String) {
+            description("missing column")
+            display("missing column: '{}'", t)
+        }
+        ExtraColumn(t: String) {
+            description("extra column")
+            display("column '{}' is not in the schema", t)
+        }
+        ColumnTypeMismatch(column: String, expected: String, actual: String) {
+            description("column type mismatch.")
+            display("column '{}' should hold {} entries, found {}", column, expected, actual)
+        }
+        UnexpectedEmpty(column: String, index: String) {
+            description("unexpected empty entry.")
+            display("column '{}' is not nullable, but is empty at index '{}'", column, index)
+        }
+        InvalidCast(value: String, column: String) {
+            description("invalid cast.")
+            display("cannot cast '{}' in column '{}'", value, column)
+        }
+        DuplicateCategory(t: String) {
+            description("duplicate category.")
+            display("duplicate category: '{}'", t)
+        }
+        InvalidSampleSize(t: String) {
+            description("invalid sample size.")
+            display("invalid sample size: {}", t)
+        }
+        TooManyBuckets(n: i64, max: i64) {
+            description("too many buckets.")
+            display("resampling would make {} buckets, more than the limit of {}", n, max)
+        }
+        InvalidOffset(offset: i32) {
+            description("invalid UTC offset.")
+            display("UTC offset of {} seconds is not less than a day", offset)
+        }
     }
 
 
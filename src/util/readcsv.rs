@@ -12,6 +12,10 @@ use util::traits::UtahNum;
 use util::error::*;
 use util::traits::Constructor;
 use rustc_serialize::Decodable;
+use mixedtypes::InnerType;
+use datetime::Datetime;
+use schema::Schema;
+use util::traits::ToSchema;
 
 use csv;
 
@@ -41,3 +45,109 @@ impl<T> ReadCSV<T> for DataFrame<T>
         DataFrame::new(matrix).columns(&columns[..])
     }
 }
+
+pub trait ReadCSVDatetime {
+    fn read_csv_with_dates(file: &'static str,
+                           date_columns: &[&str],
+                           format: &str)
+                           -> Result<DataFrame<InnerType>>;
+}
+
+impl ReadCSVDatetime for DataFrame<InnerType> {
+    /// Read a csv file of mixed types, parsing the entries of `date_columns` as datetimes in
+    /// `format` (see `Datetime::parse`). Other entries are inferred like `InnerType::from_str`,
+    /// and empty entries are left empty.
+    fn read_csv_with_dates(file: &'static str,
+                           date_columns: &[&str],
+                           format: &str)
+                           -> Result<DataFrame<InnerType>> {
+        let mut rdr = csv::Reader::from_file(file)
+            .map_err(|e| ErrorKind::ParseError(e.to_string()))?;
+        let columns = rdr.headers().map_err(|e| ErrorKind::ParseError(e.to_string()))?;
+        if let Some(name) = date_columns.iter().find(|x| !columns.iter().any(|c| c == *x)) {
+            return Err(ErrorKind::InvalidColumnName(name.to_string()).into());
+        }
+        let is_date: Vec<bool> = columns.iter().map(|x| date_columns.contains(&&x[..])).collect();
+
+        let (mut nrow, ncol) = (0, columns.len());
+        let mut v: Vec<InnerType> = Vec::new();
+        for record in rdr.records() {
+            nrow += 1;
+            let record = record.map_err(|e| ErrorKind::ParseError(e.to_string()))?;
+            for (s, &date) in record.iter().zip(is_date.iter()) {
+                v.push(if s.is_empty() {
+                    InnerType::Empty
+                } else if date {
+                    InnerType::Datetime(Datetime::parse(s, format)?)
+                } else {
+                    s.parse()?
+                });
+            }
+        }
+
+        let matrix = Array::from_shape_vec((nrow, ncol), v).unwrap();
+        DataFrame::new(matrix).columns(&columns[..])
+    }
+}
+
+pub trait ReadCSVSchema {
+    fn read_csv_with_schema(file: &'static str, schema: &Schema) -> Result<DataFrame<InnerType>>;
+}
+
+impl ReadCSVSchema for DataFrame<InnerType> {
+    /// Read a csv file of mixed types that must satisfy `schema`. Entries of typed columns are
+    /// parsed as their type, and the others are inferred like `InnerType::from_str`. Empty
+    /// entries are left empty. The read fails on the first column that is missing or not in the
+    /// schema, entry that doesn't parse, or empty entry of a column that isn't nullable.
+    fn read_csv_with_schema(file: &'static str,
+                            schema: &Schema)
+                            -> Result<DataFrame<InnerType>> {
+        let mut rdr = csv::Reader::from_file(file)
+            .map_err(|e| ErrorKind::ParseError(e.to_string()))?;
+        let columns = rdr.headers().map_err(|e| ErrorKind::ParseError(e.to_string()))?;
+        if let Some(name) = schema.names().into_iter().find(|x| !columns.iter().any(|c| c == x)) {
+            return Err(ErrorKind::MissingColumn(name.to_string()).into());
+        }
+        let fields = columns.iter()
+            .map(|x| schema.get(x).ok_or_else(|| ErrorKind::ExtraColumn(x.clone()).into()))
+            .collect::<Result<Vec<_>>>()?;
+
+        let (mut nrow, ncol) = (0, columns.len());
+        let mut v: Vec<InnerType> = Vec::new();
+        for record in rdr.records() {
+            nrow += 1;
+            let record = record.map_err(|e| ErrorKind::ParseError(e.to_string()))?;
+            for (s, field) in record.iter().zip(fields.iter()) {
+                if s.is_empty() {
+                    v.push(InnerType::Empty);
+                    continue;
+                }
+                let x: InnerType = s.parse()?;
+                v.push(match (field.dtype, x.dtype()) {
+                    // "NaN" is read as an empty entry, so `validate` rejects it in a column
+                    // that isn't nullable.
+                    (_, None) => InnerType::Empty,
+                    (None, Some(_)) => x,
+                    (Some(dtype), Some(actual)) => {
+                        // An entry only has the type if it converts back to itself, so that
+                        // "1.5" is not truncated into an integer column.
+                        match InnerType::from(&s[..]).to_dtype_exact(dtype) {
+                            Some(y) => y,
+                            None => {
+                                return Err(ErrorKind::ColumnTypeMismatch(field.name.clone(),
+                                                                         dtype.to_string(),
+                                                                         actual.to_string())
+                                    .into())
+                            }
+                        }
+                    }
+                });
+            }
+        }
+
+        let matrix = Array::from_shape_vec((nrow, ncol), v).unwrap();
+        let df = DataFrame::new(matrix).columns(&columns[..])?;
+        df.validate(schema)?;
+        Ok(df)
+    }
+}
@@ -4,12 +4,22 @@ use std::iter::Iterator;
 use combinators::aggregate::*;
 use combinators::transform::*;
 use combinators::process::*;
+use combinators::lag::*;
+use combinators::rolling::*;
+use combinators::resample::*;
 use dataframe::{DataFrame, DataFrameMut, DataFrameIterator, DataFrameMutIterator};
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
 use util::error::*;
-use std::ops::{Add, Sub, Mul, Div};
+use std::ops::{Add, Sub, Mul, Div, Range};
 use num::traits::{One, Zero};
 use ndarray::ArrayView1;
+use rand::distributions::IndependentSample;
+use mixedtypes::{InnerType, OuterType, DType};
+use datetime::Datetime;
+use categorical::Categorical;
+use schema::Schema;
+use multiindex::MultiIndexFrame;
 
 pub trait UtahNum
     : Add<Output = Self> +
@@ -41,99 +51,340 @@ impl<T> UtahNum for T
 }
 
 
+/// A label of a row or column. Labels are kept in order and looked up by hash, and a label
+/// can be made up from a position when a dataframe is created without one.
+pub trait UtahLabel: Hash + Ord + Clone + Debug + Display {
+    fn from_position(position: usize) -> Self;
+}
+
 pub trait Empty<T> {
     fn empty() -> T;
     fn is_empty(&self) -> bool;
 }
 
-pub trait Constructor<'a, T>
+pub trait ToHashKey {
+    fn hash_key(&self) -> HashKey;
+}
+
+/// The type of an entry, as it is checked against a schema.
+pub trait ToDType {
+    /// The type of every entry, if the element type only holds entries of one type.
+    fn element_dtype() -> Option<DType>;
+    /// The type of this entry, or `None` if it is empty.
+    fn dtype(&self) -> Option<DType>;
+}
+
+/// Conversion of an entry to another element type, or `None` if it has no value of that type.
+pub trait Cast<U> {
+    fn cast(&self) -> Option<U>;
+    /// Like `cast`, but `None` unless the value survives the cast unchanged.
+    fn cast_exact(&self) -> Option<U>;
+}
+
+pub trait Constructor<'a, T, L = String>
     where T: 'a + UtahNum,
+          L: 'a + UtahLabel,
           Self: Sized
 {
-    fn new<U: Clone + Debug>(data: Matrix<U>) -> DataFrame<T> where T: From<U>;
-    fn from_array<U: Clone>(data: Row<U>, axis: UtahAxis) -> DataFrame<T> where T: From<U>;
-    fn index<U: Clone>(self, index: &'a [U]) -> Result<Self> where String: From<U>;
-    fn columns<U: Clone>(self, columns: &'a [U]) -> Result<Self> where String: From<U>;
-    fn df_iter(&'a self, axis: UtahAxis) -> DataFrameIterator<'a, T>;
-    fn df_iter_mut(&'a mut self, axis: UtahAxis) -> DataFrameMutIterator<'a, T>;
+    fn new<U: Clone + Debug>(data: Matrix<U>) -> DataFrame<T, L> where T: From<U>;
+    fn from_array<U: Clone>(data: Row<U>, axis: UtahAxis) -> DataFrame<T, L> where T: From<U>;
+    fn random<D>(shape: (usize, usize), distribution: D, seed: u64) -> DataFrame<T, L>
+        where D: IndependentSample<T>;
+    fn from_fn<F>(shape: (usize, usize), f: F) -> DataFrame<T, L> where F: Fn(usize, usize) -> T;
+    fn index<U: Clone>(self, index: &'a [U]) -> Result<Self> where L: From<U>;
+    fn columns<U: Clone>(self, columns: &'a [U]) -> Result<Self> where L: From<U>;
+    fn df_iter(&'a self, axis: UtahAxis) -> DataFrameIterator<'a, T, L>;
+    fn df_iter_mut(&'a mut self, axis: UtahAxis) -> DataFrameMutIterator<'a, T, L>;
 }
 
 
-pub trait Operations<'a, T>
-    where T: 'a + UtahNum
+pub trait Operations<'a, T, L = String>
+    where T: 'a + UtahNum,
+          L: 'a + UtahLabel
 {
     fn shape(self) -> (usize, usize);
-    fn select<U: ?Sized>(&'a self, names: &'a [&'a U], axis: UtahAxis) -> SelectIter<'a, T>
-        where String: From<&'a U>;
-    fn remove<U: ?Sized>(&'a self, names: &'a [&'a U], axis: UtahAxis) -> RemoveIter<'a, T>
-        where String: From<&'a U>;
-    fn append<U: ?Sized>(&'a mut self,
+    fn transpose(self) -> DataFrame<T, L>;
+    fn t(&'a self) -> DataFrame<T, L>;
+    fn head(&'a self, n: usize) -> DataFrame<T, L>;
+    fn tail(&'a self, n: usize) -> DataFrame<T, L>;
+    fn slice(&'a self, range: Range<usize>, axis: UtahAxis) -> DataFrame<T, L>;
+    fn take(&'a self, positions: &[usize], axis: UtahAxis) -> Result<DataFrame<T, L>>;
+    fn select<U: ?Sized + ToOwned>(&'a self, names: &'a [&'a U], axis: UtahAxis) -> SelectIter<'a, T, L>
+        where L: From<U::Owned>;
+    fn remove<U: ?Sized + ToOwned>(&'a self, names: &'a [&'a U], axis: UtahAxis) -> RemoveIter<'a, T, L>
+        where L: From<U::Owned>;
+    fn append<U: ?Sized + ToOwned>(&'a mut self,
                          name: &'a U,
                          data: ArrayView1<'a, T>,
                          axis: UtahAxis)
-                         -> AppendIter<'a, T>
-        where String: From<&'a U>;
-    fn inner_left_join(&'a self, other: &'a DataFrame<T>) -> InnerJoinIter<'a, T>;
-    fn outer_left_join(&'a self, other: &'a DataFrame<T>) -> OuterJoinIter<'a, T>;
-    fn inner_right_join(&'a self, other: &'a DataFrame<T>) -> InnerJoinIter<'a, T>;
-    fn outer_right_join(&'a self, other: &'a DataFrame<T>) -> OuterJoinIter<'a, T>;
-    fn concat(&'a self, other: &'a DataFrame<T>, axis: UtahAxis) -> ConcatIter<'a, T>;
-    fn sumdf(&'a mut self, axis: UtahAxis) -> SumIter<'a, T>;
-    fn mean(&'a mut self, axis: UtahAxis) -> MeanIter<'a, T>;
-    fn maxdf(&'a mut self, axis: UtahAxis) -> MaxIter<'a, T>;
-    fn mindf(&'a mut self, axis: UtahAxis) -> MinIter<'a, T>;
-    fn mapdf<F>(&'a mut self, f: F, axis: UtahAxis) -> MapDFIter<'a, T, F>
+                         -> AppendIter<'a, T, L>
+        where L: From<U::Owned>;
+    fn inner_left_join(&'a self, other: &'a DataFrame<T, L>) -> InnerJoinIter<'a, T, L>;
+    fn outer_left_join(&'a self, other: &'a DataFrame<T, L>) -> OuterJoinIter<'a, T, L>;
+    fn inner_right_join(&'a self, other: &'a DataFrame<T, L>) -> InnerJoinIter<'a, T, L>;
+    fn outer_right_join(&'a self, other: &'a DataFrame<T, L>) -> OuterJoinIter<'a, T, L>;
+    fn concat(&'a self, other: &'a DataFrame<T, L>, axis: UtahAxis) -> ConcatIter<'a, T, L>;
+    fn sumdf(&'a mut self, axis: UtahAxis) -> SumIter<'a, T, L>;
+    fn mean(&'a mut self, axis: UtahAxis) -> MeanIter<'a, T, L>;
+    fn maxdf(&'a mut self, axis: UtahAxis) -> MaxIter<'a, T, L>;
+    fn mindf(&'a mut self, axis: UtahAxis) -> MinIter<'a, T, L>;
+    fn countdf(&'a self, axis: UtahAxis) -> CountIter<'a, T, L>;
+    fn prod(&'a self, axis: UtahAxis) -> ProdIter<'a, T, L>;
+    fn median(&'a self, axis: UtahAxis) -> MedianIter<'a, T, L>;
+    fn quantile(&'a self, q: f64, interpolation: Interpolation, axis: UtahAxis) -> Result<QuantileIter<'a, T, L>>;
+    fn var(&'a self, axis: UtahAxis) -> VarIter<'a, T, L>;
+    fn mode(&'a self, axis: UtahAxis) -> ModeIter<'a, T, L>;
+    fn nunique(&'a self, axis: UtahAxis) -> NUniqueIter<'a, T, L>;
+    fn idxmax(&'a self, axis: UtahAxis) -> IdxMaxIter<'a, T, L>;
+    fn idxmin(&'a self, axis: UtahAxis) -> IdxMinIter<'a, T, L>;
+    fn shift(&'a self, n: isize, axis: UtahAxis) -> ShiftIter<'a, T, L>;
+    fn diff(&'a self, n: isize, axis: UtahAxis) -> DiffIter<'a, T, L>;
+    fn pct_change(&'a self, n: isize, axis: UtahAxis) -> PctChangeIter<'a, T, L>;
+    fn mapdf<F>(&'a mut self, f: F, axis: UtahAxis) -> MapDFIter<'a, T, F, L>
         where F: Fn(T) -> T,
               for<'r> F: Fn(T) -> T;
-    fn impute(&'a mut self, strategy: ImputeStrategy, axis: UtahAxis) -> ImputeIter<'a, T>;
+    fn impute(&'a mut self, strategy: ImputeStrategy, axis: UtahAxis) -> ImputeIter<'a, T, L>;
+    fn cumsum(&'a mut self, axis: UtahAxis) -> CumSumIter<'a, T, L>;
+    fn cumprod(&'a mut self, axis: UtahAxis) -> CumProdIter<'a, T, L>;
+    fn cummax(&'a mut self, axis: UtahAxis) -> CumMaxIter<'a, T, L>;
+    fn cummin(&'a mut self, axis: UtahAxis) -> CumMinIter<'a, T, L>;
+    fn rolling(&'a self, window: usize, min_periods: usize, center: bool) -> Rolling<'a, T, L>;
+    fn expanding(&'a self, min_periods: usize) -> Expanding<'a, T, L>;
+    fn ewm(&'a self, decay: Decay, adjust: bool) -> Ewm<'a, T, L>;
 }
 
-pub trait Aggregate<'a, T>
+pub trait Describe<T, L = String>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    fn describe(&self) -> Result<DataFrame<T, L>>;
+}
+
+pub trait Correlation<L = String>
+    where L: UtahLabel
+{
+    fn corr(&self, method: CorrelationMethod) -> Result<DataFrame<f64, L>>;
+    fn cov(&self) -> Result<DataFrame<f64, L>>;
+}
+
+pub trait Sample<T, L = String>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    fn sample(&self, size: SampleSize, replace: bool, seed: u64) -> Result<DataFrame<T, L>>;
+    fn shuffle(&self, seed: u64) -> DataFrame<T, L>;
+    fn train_test_split(&self,
+                        frac: f64,
+                        stratify_on: Option<&str>,
+                        seed: u64)
+                        -> Result<(DataFrame<T, L>, DataFrame<T, L>)>;
+}
+
+pub trait Duplicates<T, L = String>
+    where T: UtahNum + ToHashKey,
+          L: UtahLabel
+{
+    fn duplicated(&self, subset: Option<&[&str]>, keep: Keep) -> Result<Vec<(L, bool)>>;
+    fn drop_duplicates(&self, subset: Option<&[&str]>, keep: Keep) -> Result<DataFrame<T, L>>;
+    fn unique(&self, column: &str) -> Result<Row<T>>;
+}
+
+pub trait Frequency<T>
+    where T: UtahNum + ToHashKey
+{
+    fn value_counts(&self,
+                    column: &str,
+                    normalize: bool,
+                    sort: bool)
+                    -> Result<DataFrame<f64, OuterType>>;
+    fn crosstab(&self, a: &str, b: &str) -> Result<DataFrame<f64, OuterType>>;
+}
+
+pub trait Reshape<T>
+    where T: UtahNum + ToHashKey
+{
+    fn pivot(&self, index: &str, columns: &str, values: &str) -> Result<MultiIndexFrame<T>>;
+    fn pivot_table(&self,
+                   index: &str,
+                   columns: &str,
+                   values: &str,
+                   aggfunc: AggFunc,
+                   fill_value: Option<T>)
+                   -> Result<MultiIndexFrame<T>>
+        where T: PartialOrd;
+    fn melt(&self, id_vars: &[&str], value_vars: Option<&[&str]>) -> Result<DataFrame<InnerType>>
+        where InnerType: From<T>;
+}
+
+pub trait Hierarchical<T>
+    where T: UtahNum + ToHashKey
+{
+    fn set_index(&self, keys: &[&str]) -> Result<MultiIndexFrame<T>>;
+    fn groupby(&self, keys: &[&str], aggfunc: AggFunc) -> Result<MultiIndexFrame<T>>
+        where T: PartialOrd;
+}
+
+pub trait DatetimeIndex {
+    fn year(&self) -> Vec<i32>;
+    fn month(&self) -> Vec<u32>;
+    fn day(&self) -> Vec<u32>;
+    fn weekday(&self) -> Vec<u32>;
+}
+
+pub trait TimeSeries<T>
     where T: UtahNum
 {
-    fn sumdf(self) -> Sum<'a, Self, T> where Self: Sized + Iterator<Item = Window<'a, T>>;
+    fn datetime_index(&self) -> Result<Vec<Datetime>>;
+    fn loc_range(&self, range: Range<&str>) -> Result<DataFrame<T, OuterType>>;
+    fn resample<'a>(&'a self, freq: &str) -> Result<Resampler<'a, T>>;
+}
+
+pub trait Modify<T, L = String>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    fn insert_column<U>(&mut self, name: U, position: usize, data: ArrayView1<T>) -> Result<()>
+        where L: From<U>;
+    fn set_column<U>(&mut self, name: U, data: ArrayView1<T>) -> Result<()> where L: From<U>;
+    fn rename<U: Clone>(&mut self, map: &[(U, U)], axis: UtahAxis) -> Result<()>
+        where L: From<U>;
+    fn drop<U: Clone>(&mut self, names: &[U], axis: UtahAxis) -> Result<()> where L: From<U>;
+}
+
+pub trait AsType<T, L = String>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    fn astype<U>(&self, mode: CastMode) -> Result<DataFrame<U, L>>
+        where T: Cast<U>,
+              U: UtahNum;
+}
 
-    fn maxdf(self) -> Max<'a, Self, T> where Self: Sized + Iterator<Item = Window<'a, T>>;
+pub trait CastColumn {
+    fn cast_column(&self,
+                   column: &str,
+                   dtype: DType,
+                   mode: CastMode)
+                   -> Result<DataFrame<InnerType>>;
+}
 
-    fn mindf(self) -> Min<'a, Self, T> where Self: Sized + Iterator<Item = Window<'a, T>>;
+pub trait ToSchema {
+    fn schema(&self) -> Schema;
+    fn validate(&self, schema: &Schema) -> Result<()>;
+}
+
+pub trait ToCategorical {
+    fn to_categorical(&self, column: &str) -> Result<Categorical>;
+}
 
-    fn mean(self) -> Mean<'a, Self, T> where Self: Sized + Iterator<Item = Window<'a, T>>;
+pub trait ToDatetimeIndex {
+    fn to_datetime_index(&self, column: &str) -> Result<DataFrame<InnerType, OuterType>>;
 }
 
-pub trait Process<'a, T, F>
+pub trait RandomMixed {
+    fn random_mixed(shape: (usize, usize),
+                    empty_frac: f64,
+                    seed: u64)
+                    -> Result<DataFrame<InnerType>>;
+}
+
+pub trait Aggregate<'a, T, L = String>
     where T: UtahNum,
-          F: Fn(T) -> T
+          L: UtahLabel
 {
-    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T>
-        where Self: Sized + Iterator<Item = WindowMut<'a, T>>;
-    fn to_mut_df(self) -> DataFrameMut<'a, T> where Self: Sized + Iterator<Item = WindowMut<'a, T>>;
-    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F>
-        where Self: Sized + Iterator<Item = WindowMut<'a, T>> + Clone;
+    fn sumdf(self) -> Sum<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn maxdf(self) -> Max<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn mindf(self) -> Min<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn mean(self) -> Mean<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn countdf(self) -> Count<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn prod(self) -> Prod<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn median(self) -> Median<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn quantile(self, q: f64, interpolation: Interpolation) -> Result<Quantile<'a, Self, T, L>>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn var(self) -> Var<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn mode(self) -> Mode<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn nunique(self) -> NUnique<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn idxmax(self) -> IdxMax<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+
+    fn idxmin(self) -> IdxMin<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+}
+
+pub trait Lag<'a, T, L = String>
+    where T: UtahNum,
+          L: UtahLabel
+{
+    fn shift(self, n: isize) -> Shift<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+    fn diff(self, n: isize) -> Diff<'a, Self, T, L> where Self: Sized + Iterator<Item = Window<'a, T, L>>;
+    fn pct_change(self, n: isize) -> PctChange<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>>;
 }
 
-pub trait Transform<'a, T>
-    where T: UtahNum + 'a
+pub trait Process<'a, T, F, L = String>
+    where T: UtahNum,
+          F: Fn(T) -> T,
+          L: UtahLabel
+{
+    fn impute(self, strategy: ImputeStrategy) -> Impute<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>;
+    fn to_mut_df(self) -> DataFrameMut<'a, T, L> where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>;
+    fn mapdf(self, f: F) -> MapDF<'a, T, Self, F, L>
+        where Self: Sized + Iterator<Item = WindowMut<'a, T, L>> + Clone;
+}
+
+pub trait Cumulative<'a, T, L = String>
+    where T: UtahNum,
+          L: UtahLabel
 {
-    fn select<U: ?Sized>(self, names: &'a [&'a U]) -> Select<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone,
-              String: From<&'a U>,
+    fn cumsum(self) -> CumSum<'a, Self, T, L> where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>;
+    fn cumprod(self) -> CumProd<'a, Self, T, L> where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>;
+    fn cummax(self) -> CumMax<'a, Self, T, L> where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>;
+    fn cummin(self) -> CumMin<'a, Self, T, L> where Self: Sized + Iterator<Item = WindowMut<'a, T, L>>;
+}
+
+pub trait Transform<'a, T, L = String>
+    where T: UtahNum + 'a,
+          L: UtahLabel
+{
+    fn select<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Select<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone,
+              L: From<U::Owned>,
               T: 'a;
-    fn remove<U: ?Sized>(self, names: &'a [&'a U]) -> Remove<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone,
-              String: From<&'a U>,
+    fn remove<U: ?Sized + ToOwned>(self, names: &'a [&'a U]) -> Remove<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone,
+              L: From<U::Owned>,
               T: 'a;
-    fn append<U: ?Sized>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T>
-        where Self: Sized + Iterator<Item = Window<'a, T>> + Clone,
-              String: From<&'a U>,
+    fn append<U: ?Sized + ToOwned>(self, name: &'a U, data: ArrayView1<'a, T>) -> Append<'a, Self, T, L>
+        where Self: Sized + Iterator<Item = Window<'a, T, L>> + Clone,
+              L: From<U::Owned>,
               T: 'a;
 }
 
 
 
-pub trait ToDataFrame<'a, I, T>
-    where T: UtahNum + 'a
+pub trait ToDataFrame<'a, I, T, L = String>
+    where T: UtahNum + 'a,
+          L: UtahLabel
 {
-    fn as_df(self) -> Result<DataFrame<T>> where Self: Sized + Iterator<Item = I>;
+    fn as_df(self) -> Result<DataFrame<T, L>> where Self: Sized + Iterator<Item = I>;
     fn as_matrix(self) -> Result<Matrix<T>> where Self: Sized + Iterator<Item = I>;
     fn as_array(self) -> Result<Row<T>> where Self: Sized + Iterator<Item = I>;
+
+    /// Collect the chain into a dataframe with its index and columns swapped, so that a
+    /// column-wise result comes out as a row.
+    fn as_transposed_df(self) -> Result<DataFrame<T, L>>
+        where Self: Sized + Iterator<Item = I>
+    {
+        Ok(self.as_df()?.transpose())
+    }
 }
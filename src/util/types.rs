@@ -3,7 +3,10 @@ use combinators::transform::*;
 use combinators::interact::*;
 use combinators::aggregate::*;
 use combinators::process::*;
+use combinators::lag::*;
 use std::iter::Chain;
+use std::cmp::Ordering;
+use datetime::Datetime;
 use dataframe::{DataFrameIterator, DataFrameMutIterator};
 
 
@@ -18,6 +21,145 @@ pub enum ImputeStrategy {
     Mean,
 }
 
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum CorrelationMethod {
+    Pearson,
+    Spearman,
+    Kendall,
+}
+
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum Interpolation {
+    Linear,
+    Lower,
+    Higher,
+    Midpoint,
+    Nearest,
+}
+
+/// The aggregate combinator used to reduce the entries that share a cell of a pivot table.
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum AggFunc {
+    Sum,
+    Mean,
+    Count,
+    Prod,
+    Median,
+    Var,
+    Mode,
+    NUnique,
+    Max,
+    Min,
+}
+
+/// Which of a set of duplicate rows to keep: the first, the last, or none of them.
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum Keep {
+    First,
+    Last,
+    None,
+}
+
+/// What to do with an entry that can't be cast to the target type: fail, or leave it empty.
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum CastMode {
+    Strict,
+    Lossy,
+}
+
+/// A hashable stand-in for a dataframe entry. Floats are keyed by their bits, so that they can
+/// be hashed at all, and every empty value maps to the same key. Integers of every width share
+/// keys, since they convert to each other exactly, but floats are keyed apart from integers:
+/// `1.0` and `1` are different keys.
+///
+/// Keys sort numbers by value, with a float before an integer of the same value, and then
+/// booleans, strings, datetimes and the empty key.
+#[derive( Clone, Debug, Hash, PartialEq, Eq)]
+pub enum HashKey {
+    Float(u64),
+    Int(i64),
+    UInt(u64),
+    Bool(bool),
+    Str(String),
+    Datetime(Datetime),
+    Empty,
+}
+
+impl HashKey {
+    /// Where the variant sorts among the others.
+    fn rank(&self) -> u8 {
+        match *self {
+            HashKey::Float(_) => 0,
+            HashKey::Int(_) => 1,
+            HashKey::UInt(_) => 2,
+            HashKey::Bool(_) => 3,
+            HashKey::Str(_) => 4,
+            HashKey::Datetime(_) => 5,
+            HashKey::Empty => 6,
+        }
+    }
+
+    /// The value of a numeric key, as a float.
+    fn number(&self) -> Option<f64> {
+        match *self {
+            HashKey::Float(x) => Some(f64::from_bits(x)),
+            HashKey::Int(x) => Some(x as f64),
+            HashKey::UInt(x) => Some(x as f64),
+            _ => None,
+        }
+    }
+}
+
+/// Map the bits of a float to an integer that sorts like the float does, with negative floats
+/// before positive ones.
+fn float_order(bits: u64) -> u64 {
+    if bits >> 63 == 1 { !bits } else { bits | 1 << 63 }
+}
+
+impl Ord for HashKey {
+    fn cmp(&self, other: &HashKey) -> Ordering {
+        // Numbers of different types compare by value first; integers that round to the same
+        // float still compare exactly below.
+        if let (Some(x), Some(y)) = (self.number(), other.number()) {
+            let by_value = float_order(x.to_bits()).cmp(&float_order(y.to_bits()));
+            if by_value != Ordering::Equal {
+                return by_value;
+            }
+        }
+        match (self, other) {
+            (HashKey::Float(x), HashKey::Float(y)) => float_order(*x).cmp(&float_order(*y)),
+            (HashKey::Int(x), HashKey::Int(y)) => x.cmp(y),
+            (HashKey::UInt(x), HashKey::UInt(y)) => x.cmp(y),
+            (HashKey::Bool(x), HashKey::Bool(y)) => x.cmp(y),
+            (HashKey::Str(x), HashKey::Str(y)) => x.cmp(y),
+            (HashKey::Datetime(x), HashKey::Datetime(y)) => x.cmp(y),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for HashKey {
+    fn partial_cmp(&self, other: &HashKey) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// How many rows to draw when sampling: either an absolute number, or a fraction of the rows.
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum SampleSize {
+    N(usize),
+    Frac(f64),
+}
+
+/// How quickly the weights of an exponentially weighted window decay. `Alpha` is the smoothing
+/// factor itself, while `Span` and `HalfLife` are converted to one.
+#[derive( Clone, Debug, Copy, PartialEq)]
+pub enum Decay {
+    Alpha(f64),
+    Span(f64),
+    HalfLife(f64),
+}
+
 
 pub type Column<T> = Array1<T>;
 pub type Row<T> = Array1<T>;
@@ -30,20 +172,36 @@ pub type ColumnView<'a, T> = ArrayView1<'a, T>;
 
 pub type MatrixView<'a, T> = ArrayView2<'a, T>;
 
-pub type DFIter<'a, T> = DataFrameIterator<'a, T>;
-pub type AppendIter<'a, T> = Append<'a, DFIter<'a, T>, T>;
-pub type SelectIter<'a, T> = Select<'a, DFIter<'a, T>, T>;
-pub type RemoveIter<'a, T> = Remove<'a, DFIter<'a, T>, T>;
-pub type InnerJoinIter<'a, T> = InnerJoin<'a, DFIter<'a, T>, T>;
-pub type OuterJoinIter<'a, T> = OuterJoin<'a, DFIter<'a, T>, T>;
-pub type ConcatIter<'a, T> = Concat<'a, Chain<DFIter<'a, T>, DFIter<'a, T>>, T>;
-pub type SumIter<'a, T> = Sum<'a, DFIter<'a, T>, T>;
-pub type MaxIter<'a, T> = Max<'a, DFIter<'a, T>, T>;
-pub type MinIter<'a, T> = Min<'a, DFIter<'a, T>, T>;
-pub type StdevIter<'a, T> = Stdev<'a, DFIter<'a, T>, T>;
-pub type MeanIter<'a, T> = Mean<'a, DFIter<'a, T>, T>;
-pub type MapDFIter<'a, T, F> = MapDF<'a, T, DataFrameMutIterator<'a, T>, F>;
-pub type ImputeIter<'a, T> = Impute<'a, DataFrameMutIterator<'a, T>, T>;
-
-pub type WindowMut<'a, T> = (String, ArrayViewMut1<'a, T>);
-pub type Window<'a, T> = (String, ArrayView1<'a, T>);
+pub type DFIter<'a, T, L = String> = DataFrameIterator<'a, T, L>;
+pub type AppendIter<'a, T, L = String> = Append<'a, DFIter<'a, T, L>, T, L>;
+pub type SelectIter<'a, T, L = String> = Select<'a, DFIter<'a, T, L>, T, L>;
+pub type RemoveIter<'a, T, L = String> = Remove<'a, DFIter<'a, T, L>, T, L>;
+pub type InnerJoinIter<'a, T, L = String> = InnerJoin<'a, DFIter<'a, T, L>, T, L>;
+pub type OuterJoinIter<'a, T, L = String> = OuterJoin<'a, DFIter<'a, T, L>, T, L>;
+pub type ConcatIter<'a, T, L = String> = Concat<'a, Chain<DFIter<'a, T, L>, DFIter<'a, T, L>>, T, L>;
+pub type SumIter<'a, T, L = String> = Sum<'a, DFIter<'a, T, L>, T, L>;
+pub type MaxIter<'a, T, L = String> = Max<'a, DFIter<'a, T, L>, T, L>;
+pub type MinIter<'a, T, L = String> = Min<'a, DFIter<'a, T, L>, T, L>;
+pub type StdevIter<'a, T, L = String> = Stdev<'a, DFIter<'a, T, L>, T, L>;
+pub type MeanIter<'a, T, L = String> = Mean<'a, DFIter<'a, T, L>, T, L>;
+pub type CountIter<'a, T, L = String> = Count<'a, DFIter<'a, T, L>, T, L>;
+pub type ProdIter<'a, T, L = String> = Prod<'a, DFIter<'a, T, L>, T, L>;
+pub type MedianIter<'a, T, L = String> = Median<'a, DFIter<'a, T, L>, T, L>;
+pub type QuantileIter<'a, T, L = String> = Quantile<'a, DFIter<'a, T, L>, T, L>;
+pub type VarIter<'a, T, L = String> = Var<'a, DFIter<'a, T, L>, T, L>;
+pub type ModeIter<'a, T, L = String> = Mode<'a, DFIter<'a, T, L>, T, L>;
+pub type NUniqueIter<'a, T, L = String> = NUnique<'a, DFIter<'a, T, L>, T, L>;
+pub type IdxMaxIter<'a, T, L = String> = IdxMax<'a, DFIter<'a, T, L>, T, L>;
+pub type IdxMinIter<'a, T, L = String> = IdxMin<'a, DFIter<'a, T, L>, T, L>;
+pub type ShiftIter<'a, T, L = String> = Shift<'a, DFIter<'a, T, L>, T, L>;
+pub type DiffIter<'a, T, L = String> = Diff<'a, DFIter<'a, T, L>, T, L>;
+pub type PctChangeIter<'a, T, L = String> = PctChange<'a, DFIter<'a, T, L>, T, L>;
+pub type MapDFIter<'a, T, F, L = String> = MapDF<'a, T, DataFrameMutIterator<'a, T, L>, F, L>;
+pub type ImputeIter<'a, T, L = String> = Impute<'a, DataFrameMutIterator<'a, T, L>, T, L>;
+pub type CumSumIter<'a, T, L = String> = CumSum<'a, DataFrameMutIterator<'a, T, L>, T, L>;
+pub type CumProdIter<'a, T, L = String> = CumProd<'a, DataFrameMutIterator<'a, T, L>, T, L>;
+pub type CumMaxIter<'a, T, L = String> = CumMax<'a, DataFrameMutIterator<'a, T, L>, T, L>;
+pub type CumMinIter<'a, T, L = String> = CumMin<'a, DataFrameMutIterator<'a, T, L>, T, L>;
+
+pub type WindowMut<'a, T, L = String> = (L, ArrayViewMut1<'a, T>);
+pub type Window<'a, T, L = String> = (L, ArrayView1<'a, T>);